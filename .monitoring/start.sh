@@ -56,6 +56,7 @@ echo ""
 echo "Monitoring stack started:"
 echo "  Grafana:    http://localhost:3000"
 echo "  Prometheus: http://localhost:9090"
+echo "  Alerts:     http://localhost:9090/alerts"
 echo "  Loki:       http://localhost:3100"
 echo ""
 echo "Configure Claude Code with:"
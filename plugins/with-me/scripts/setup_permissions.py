@@ -2,9 +2,9 @@
 """
 Setup permissions for with-me plugin.
 
-Adds 12 required permissions to .claude/settings.local.json:
+Adds 17 required permissions to .claude/settings.local.json:
 - 1 PYTHONPATH environment variable
-- 6 session CLI commands (init, next-question, evaluate-question, update-with-computation, status, complete)
+- 11 session CLI commands (init, next-question, evaluate-question, update-with-computation, status, complete, resolve-contradiction, set-archetype, search-questions, add-question, understanding)
 - 3 feedback CLI commands (start, record, complete)
 - 1 Skill (requirement-analysis for post-session analysis)
 - 1 Read permission (session data)
@@ -25,6 +25,11 @@ REQUIRED_PERMISSIONS = [
     "Bash(python3 -m with_me.cli.session update-with-computation*)",
     "Bash(python3 -m with_me.cli.session status*)",
     "Bash(python3 -m with_me.cli.session complete*)",
+    "Bash(python3 -m with_me.cli.session resolve-contradiction*)",
+    "Bash(python3 -m with_me.cli.session set-archetype*)",
+    "Bash(python3 -m with_me.cli.session search-questions*)",
+    "Bash(python3 -m with_me.cli.session add-question*)",
+    "Bash(python3 -m with_me.cli.session understanding*)",
     # Feedback CLI commands
     "Bash(python3 -m with_me.cli.feedback start*)",
     "Bash(python3 -m with_me.cli.feedback record*)",
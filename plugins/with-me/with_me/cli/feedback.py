@@ -10,12 +10,15 @@ import json
 import sys
 
 from with_me.lib.question_feedback_manager import QuestionFeedbackManager
+from with_me.lib.strategy_bandit import StrategyBandit
 
 
-def cmd_start(plain_output: bool = False) -> None:
-    """Start a new session"""
+def cmd_start(
+    plain_output: bool = False, with_me_session_id: str | None = None
+) -> None:
+    """Start a new session, optionally linked to a with-me session"""
     manager = QuestionFeedbackManager()
-    session_id = manager.start_session()
+    session_id = manager.start_session(with_me_session_id=with_me_session_id)
     if plain_output:
         print(session_id)
     else:
@@ -104,7 +107,9 @@ def cmd_complete(session_id: str, final_uncertainties_json: str) -> None:
 def cmd_stats() -> None:
     """Get statistics"""
     manager = QuestionFeedbackManager()
-    stats = manager.get_statistics()
+    stats = dict(manager.get_statistics())
+    stats["strategy_stats"] = StrategyBandit(manager.get_strategy_stats()).summary()
+    stats["likelihood_calibration"] = manager.get_calibration_summary()
     print(json.dumps(stats, indent=2))
 
 
@@ -112,7 +117,7 @@ def usage() -> None:
     """Print usage information"""
     print("Usage: question_feedback_cli.py <command> [args]", file=sys.stderr)
     print("\nCommands:", file=sys.stderr)
-    print("  start", file=sys.stderr)
+    print("  start [with_me_session_id]", file=sys.stderr)
     print(
         "  record <session_id> <question> <context_json> <answer_json>",
         file=sys.stderr,
@@ -124,11 +129,15 @@ def usage() -> None:
 
 def _validate_args(command: str, args: list[str]) -> None:
     """Validate command arguments (raises ValueError if invalid)"""
+    max_start_argc = 2  # command + optional with-me session ID
     expected_record_argc = 5  # command + 4 args
     expected_batch_argc = 3  # command + 2 args
     expected_complete_argc = 3  # command + 2 args
 
-    if command == "record" and len(args) != expected_record_argc:
+    if command == "start" and len(args) > max_start_argc:
+        msg = "start takes at most 1 argument"
+        raise ValueError(msg)
+    elif command == "record" and len(args) != expected_record_argc:
         msg = "record requires 4 arguments"
         raise ValueError(msg)
     elif command == "record-batch" and len(args) != expected_batch_argc:
@@ -169,7 +178,10 @@ def main() -> None:
 
     try:
         if command == "start":
-            cmd_start(plain_output=plain_output)
+            cmd_start(
+                plain_output=plain_output,
+                with_me_session_id=args[1] if len(args) > 1 else None,
+            )
         elif command == "record":
             cmd_record(args[1], args[2], args[3], args[4])
         elif command == "record-batch":
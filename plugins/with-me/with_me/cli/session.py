@@ -8,6 +8,16 @@ Provides JSON output for Claude Code to consume:
 - update: Update beliefs with answer
 - status: Display current state
 - complete: Finalize session
+- resolve-contradiction: Resolve cross-dimension contradictions
+- set-archetype: Re-seed beliefs with a project archetype prior
+- coupling: Mutual-information coupling matrix between dimensions
+- search-questions / add-question: Query and extend the question bank
+- fork-session / diff-sessions: Branch a session and compare belief states
+- draft: Render beliefs as a requirements document draft
+- understanding: Compact current-understanding block for prompt injection
+- journal: Read the append-only log of belief updates with provenance
+- export-session / import-session: Portable, versioned session snapshots
+- purge: Delete a session's state, journal, draft and feedback records
 
 Session state is persisted to .claude/with_me/sessions/<session_id>.json
 """
@@ -15,13 +25,52 @@ Session state is persisted to .claude/with_me/sessions/<session_id>.json
 import argparse
 import json
 import math
+import shutil
 import sys
-from datetime import UTC, datetime
+from collections.abc import Callable
+from datetime import UTC, datetime, timedelta
 from pathlib import Path
 from typing import Any
 
-from with_me.lib.dimension_belief import HypothesisSet, compute_jsd
+from with_me.lib.archetype_priors import (
+    apply_archetype,
+    infer_archetype,
+    load_archetypes,
+)
+from with_me.lib.dimension_belief import HypothesisSet, compute_jsd, diff_beliefs
+from with_me.lib.likelihood_models import (
+    LIKELIHOOD_MODELS,
+    calibration_by_model,
+    model_for_dimension,
+    validate_confirmed,
+)
+from with_me.lib.observation_journal import (
+    append_entry,
+    question_id,
+    read_entries,
+    restore_journal,
+)
+from with_me.lib.question_bank import (
+    BankQuestion,
+    add_question,
+    load_bank,
+    search_questions,
+    validate_templates,
+)
+from with_me.lib.question_feedback_manager import QuestionFeedbackManager
+from with_me.lib.requirements_draft import render_draft, render_understanding
 from with_me.lib.session_orchestrator import SessionOrchestrator
+from with_me.lib.session_schema import (
+    SCHEMA_VERSION,
+    export_snapshot,
+    import_snapshot,
+    is_valid_session_id,
+    migrate_state,
+)
+from with_me.lib.strategy_bandit import STRATEGIES, estimate_tokens
+
+# Fields written by complete that the orchestrator does not track
+COMPLETION_FIELDS = ("completed", "completed_at", "summary")
 
 # Convergence thresholds
 DIMENSION_RESOLVED_THRESHOLD = (
@@ -190,25 +239,130 @@ def get_session_dir() -> Path:
     return fallback
 
 
+def get_question_bank_paths() -> list[Path]:
+    """Get question bank sources: plugin seed bank, then workspace banks.
+
+    Workspace banks live next to the sessions directory:
+    - question_bank.json (questions added via add-question)
+    - questions/*.md (hand-written markdown banks)
+    """
+    plugin_root = Path(__file__).parent.parent.parent
+    workspace_dir = get_session_dir().parent
+
+    return [
+        plugin_root / "config" / "question_bank.md",
+        workspace_dir / "question_bank.json",
+        *sorted((workspace_dir / "questions").glob("*.md")),
+    ]
+
+
+def get_journal_file(session_id: str) -> Path:
+    """Get the observation journal file (journal/<session_id>.jsonl)."""
+    return get_session_dir().parent / "journal" / f"{session_id}.jsonl"
+
+
+def append_journal(session_id: str, entries: list[dict[str, Any]]) -> int:
+    """Append journal entries in order; returns the sequence of the first."""
+    journal_file = get_journal_file(session_id)
+    seqs = [append_entry(journal_file, **entry) for entry in entries]
+    return seqs[0]
+
+
+def journal_archetype(
+    session_id: str,
+    orchestrator: SessionOrchestrator,
+    archetype: str,
+    reseeded: list[str],
+    priors: dict[str, dict[str, float]],
+) -> None:
+    """Journal the prior change of each dimension re-seeded by an archetype."""
+    for dim_id in reseeded:
+        append_entry(
+            get_journal_file(session_id),
+            "archetype",
+            dim_id,
+            f"archetype:{archetype}",
+            priors[dim_id],
+            orchestrator.beliefs[dim_id].posterior,
+            question_index=orchestrator.question_count,
+        )
+
+
+def get_draft_file(session_id: str) -> Path:
+    """Get the requirements draft file (drafts/<session_id>.md)."""
+    return get_session_dir().parent / "drafts" / f"{session_id}.md"
+
+
+def write_draft(session_id: str, orchestrator: SessionOrchestrator) -> Path:
+    """Regenerate the requirements draft for a session.
+
+    Written next to the sessions directory, so an editor watching the file
+    shows a live preview.
+    """
+    open_threshold = orchestrator.config["session_config"].get(
+        "draft_open_entropy_threshold", 1.0
+    )
+    draft_file = get_draft_file(session_id)
+    draft_file.parent.mkdir(parents=True, exist_ok=True)
+    draft_file.write_text(render_draft(orchestrator, open_threshold), encoding="utf-8")
+    return draft_file
+
+
 def save_session_state(session_id: str, orchestrator: SessionOrchestrator) -> None:
-    """Save session state to disk."""
+    """Save session state to disk.
+
+    Completion fields written by complete are carried over from the file
+    on disk, so re-saving a completed session keeps it completed.
+
+    Examples:
+        >>> import contextlib, io, os, shutil, tempfile
+        >>> workspace = tempfile.mkdtemp()
+        >>> saved_cwd, saved_pwd = os.getcwd(), os.environ.get("PWD", "")
+        >>> os.chdir(workspace)
+        >>> os.environ["PWD"] = workspace
+        >>> (Path(workspace) / ".claude").mkdir()
+        >>> with contextlib.redirect_stdout(io.StringIO()) as out:
+        ...     cmd_init(argparse.Namespace(archetype=None))
+        >>> sid = json.loads(out.getvalue())["session_id"]
+        >>> with contextlib.redirect_stdout(io.StringIO()):
+        ...     cmd_complete(argparse.Namespace(session_id=sid, confirmed=None))
+        >>> save_session_state(sid, load_session_state(sid))
+        >>> state = read_session_state(sid)
+        >>> state["completed"], "summary" in state
+        (True, True)
+        >>> os.chdir(saved_cwd)
+        >>> os.environ["PWD"] = saved_pwd
+        >>> shutil.rmtree(workspace)
+    """
     session_file = get_session_dir() / f"{session_id}.json"
 
     state = {
+        "schema_version": SCHEMA_VERSION,
         "session_id": orchestrator.session_id,
         "beliefs": {k: v.to_dict() for k, v in orchestrator.beliefs.items()},
         "question_history": orchestrator.question_history,
         "question_count": orchestrator.question_count,
         "recent_information_gains": orchestrator.recent_information_gains,
         "thompson_states": orchestrator.thompson_states,
+        "contradiction_resolutions": orchestrator.contradiction_resolutions,
+        "archetype": orchestrator.archetype,
+        "pending_strategy": orchestrator.pending_strategy,
+        "forked_from": orchestrator.forked_from,
     }
+    if session_file.exists():
+        with open(session_file, encoding="utf-8") as f:
+            previous = json.load(f)
+        state.update({k: previous[k] for k in COMPLETION_FIELDS if k in previous})
 
     with open(session_file, "w", encoding="utf-8") as f:
         json.dump(state, f, indent=2, ensure_ascii=False)
 
+    # Keep the draft in sync with every state change
+    write_draft(session_id, orchestrator)
 
-def load_session_state(session_id: str) -> SessionOrchestrator:
-    """Load session state from disk."""
+
+def read_session_state(session_id: str) -> dict[str, Any]:
+    """Read a session file, migrated to the current schema."""
     session_file = get_session_dir() / f"{session_id}.json"
 
     if not session_file.exists():
@@ -223,41 +377,119 @@ def load_session_state(session_id: str) -> SessionOrchestrator:
     with open(session_file) as f:
         state = json.load(f)
 
-    # Reconstruct orchestrator
+    # Bring files written by older versions up to the current schema
+    try:
+        state = migrate_state(state)
+    except ValueError as e:
+        print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    return state
+
+
+def restore_orchestrator(state: dict[str, Any]) -> SessionOrchestrator:
+    """Reconstruct an orchestrator from migrated session state."""
     orch = SessionOrchestrator()
     orch.session_id = state["session_id"]
     orch.beliefs = {k: HypothesisSet.from_dict(v) for k, v in state["beliefs"].items()}
     orch.question_history = state["question_history"]
     orch.question_count = state["question_count"]
-    orch.recent_information_gains = state.get("recent_information_gains", [])
-    orch.thompson_states = state.get("thompson_states", {})
+    orch.recent_information_gains = state["recent_information_gains"]
+    orch.thompson_states = state["thompson_states"]
+    orch.contradiction_resolutions = state["contradiction_resolutions"]
+    orch.archetype = state["archetype"]
+    orch.pending_strategy = state["pending_strategy"]
+    orch.forked_from = state["forked_from"]
 
     return orch
 
 
+def exit_if_completed(state: dict[str, Any]) -> None:
+    """Refuse commands that would change a completed session."""
+    if state.get("completed"):
+        print(
+            json.dumps(
+                {"error": f"Session already completed: {state['session_id']}"},
+                ensure_ascii=False,
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+
+def load_session_state(session_id: str) -> SessionOrchestrator:
+    """Load session state from disk."""
+    return restore_orchestrator(read_session_state(session_id))
+
+
 def cmd_init(args: argparse.Namespace) -> None:
     """Initialize new session."""
     orch = SessionOrchestrator()
     session_id = orch.initialize_session()
 
+    output: dict[str, Any] = {
+        "session_id": session_id,
+        "status": "initialized",
+    }
+
+    # Optional archetype prior instead of uniform beliefs
+    if args.archetype is not None:
+        archetypes = load_archetypes()["archetypes"]
+        priors = {k: dict(v.posterior) for k, v in orch.beliefs.items()}
+        try:
+            output["reseeded_dimensions"] = apply_archetype(
+                orch.beliefs, archetypes, args.archetype
+            )
+        except ValueError as e:
+            print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
+            sys.exit(1)
+        orch.archetype = args.archetype
+        output["archetype"] = args.archetype
+
     # Save initial state
     save_session_state(session_id, orch)
+    if args.archetype is not None:
+        journal_archetype(
+            session_id, orch, args.archetype, output["reseeded_dimensions"], priors
+        )
 
     # Output JSON
-    print(
-        json.dumps(
-            {
-                "session_id": session_id,
-                "status": "initialized",
-            },
-            ensure_ascii=False,
-        )
-    )
+    print(json.dumps(output, ensure_ascii=False))
 
 
 def cmd_next_question(args: argparse.Namespace) -> None:
-    """Select next question to ask."""
-    orch = load_session_state(args.session_id)
+    """Select next question to ask.
+
+    Completed sessions are refused: selecting a question records the
+    pending strategy, which would reopen the session.
+
+    Examples:
+        >>> import contextlib, io, os, shutil, tempfile
+        >>> workspace = tempfile.mkdtemp()
+        >>> saved_cwd, saved_pwd = os.getcwd(), os.environ.get("PWD", "")
+        >>> os.chdir(workspace)
+        >>> os.environ["PWD"] = workspace
+        >>> (Path(workspace) / ".claude").mkdir()
+        >>> with contextlib.redirect_stdout(io.StringIO()) as out:
+        ...     cmd_init(argparse.Namespace(archetype=None))
+        >>> sid = json.loads(out.getvalue())["session_id"]
+        >>> with contextlib.redirect_stdout(io.StringIO()):
+        ...     cmd_complete(argparse.Namespace(session_id=sid, confirmed=None))
+        >>> with contextlib.redirect_stderr(io.StringIO()) as err:
+        ...     try:
+        ...         cmd_next_question(argparse.Namespace(session_id=sid, strategy=None))
+        ...     except SystemExit as e:
+        ...         print(e.code, err.getvalue().strip())
+        1 {"error": "Session already completed: ..."}
+        >>> read_session_state(sid)["completed"]
+        True
+        >>> os.chdir(saved_cwd)
+        >>> os.environ["PWD"] = saved_pwd
+        >>> shutil.rmtree(workspace)
+    """
+    state = read_session_state(args.session_id)
+    exit_if_completed(state)
+    orch = restore_orchestrator(state)
 
     # Check convergence first
     if orch.check_convergence():
@@ -272,7 +504,9 @@ def cmd_next_question(args: argparse.Namespace) -> None:
         )
         return
 
-    dimension, question = orch.select_next_question()
+    # Strategy layer: explicit override, otherwise the cross-session bandit
+    strategy = args.strategy or orch.select_strategy()
+    dimension, question = orch.select_next_question(strategy)
 
     if dimension is None:
         print(
@@ -286,6 +520,10 @@ def cmd_next_question(args: argparse.Namespace) -> None:
         )
         return
 
+    # Remember the strategy so update-with-computation can credit it
+    orch.pending_strategy = strategy
+    save_session_state(args.session_id, orch)
+
     # Get dimension metadata with detailed information for question generation
     dim_config = orch.config["dimensions"][dimension]
 
@@ -320,6 +558,7 @@ def cmd_next_question(args: argparse.Namespace) -> None:
                 "focus_areas": dim_config.get("focus_areas", []),
                 "hypotheses": hypotheses_info,
                 "question": question,
+                "strategy": strategy,
                 "supports_multi_select": dim_config.get("supports_multi_select", False),
                 "importance": dim_config.get("importance", 0.5),
                 "posterior": hs.posterior,
@@ -327,6 +566,7 @@ def cmd_next_question(args: argparse.Namespace) -> None:
                 "aleatoric_entropy": round(decomp["aleatoric"], 4),
                 "epistemic_ratio": round(decomp["epistemic_ratio"], 4),
                 "suggested_secondary_dimensions": suggested_secondary,
+                "batch_with": orch.get_batch_candidates(dimension),
             },
             ensure_ascii=False,
         )
@@ -374,21 +614,8 @@ def cmd_status(args: argparse.Namespace) -> None:
 
 def cmd_complete(args: argparse.Namespace) -> None:
     """Complete session and generate summary."""
-    session_file = get_session_dir() / f"{args.session_id}.json"
-
-    if not session_file.exists():
-        print(
-            json.dumps(
-                {"error": f"Session file not found: {args.session_id}"},
-                ensure_ascii=False,
-            ),
-            file=sys.stderr,
-        )
-        sys.exit(1)
-
-    # Load session data
-    with open(session_file, encoding="utf-8") as f:
-        session_data = json.load(f)
+    session_data = read_session_state(args.session_id)
+    exit_if_completed(session_data)
 
     # Calculate summary from question_history
     question_history = session_data.get("question_history", [])
@@ -420,6 +647,23 @@ def cmd_complete(args: argparse.Namespace) -> None:
         if entropy < DIMENSION_RESOLVED_THRESHOLD
     ]
 
+    # Likelihood model calibration against hypotheses the user confirmed
+    orch = restore_orchestrator(session_data)
+    try:
+        confirmed = json.loads(args.confirmed or "{}")
+    except json.JSONDecodeError as e:
+        error = {"error": f"Invalid JSON in --confirmed: {e}"}
+        print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+    errors = validate_confirmed(confirmed, orch.config["dimensions"])
+    if errors:
+        print(json.dumps({"error": errors}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    calibration = calibration_by_model(question_history, confirmed)
+    if calibration:
+        orch.manager.record_calibration(calibration)
+
     summary = {
         "total_questions": total_questions,
         "avg_reward_per_question": avg_reward,
@@ -429,6 +673,7 @@ def cmd_complete(args: argparse.Namespace) -> None:
         "session_efficiency": total_info_gain / total_questions
         if total_questions > 0
         else 0,
+        "calibration": calibration,
     }
 
     # Add completion metadata
@@ -437,6 +682,7 @@ def cmd_complete(args: argparse.Namespace) -> None:
     session_data["summary"] = summary
 
     # Save updated session with completion status
+    session_file = get_session_dir() / f"{args.session_id}.json"
     with open(session_file, "w", encoding="utf-8") as f:
         json.dump(session_data, f, indent=2, ensure_ascii=False)
 
@@ -532,12 +778,23 @@ def cmd_evaluate_question(args: argparse.Namespace) -> None:
     )
 
 
+def cache_uncertainty(hs: HypothesisSet, entropy: float) -> None:
+    """Cache entropy and confidence for session_orchestrator methods."""
+    h_max = math.log2(len(hs.hypotheses))
+    hs._cached_entropy = entropy
+    hs._cached_confidence = 1.0 - (entropy / h_max) if h_max > 0 else 1.0
+
+
 def _apply_secondary_updates(
     args: argparse.Namespace,
     orch: SessionOrchestrator,
+    pending_journal: list[dict[str, Any]],
 ) -> list[dict[str, Any]]:
     """Apply cross-dimension secondary updates with reduced weight.
 
+    Journal entries for the updates are added to pending_journal; the caller
+    appends them once the session state has been saved.
+
     Returns:
         List of secondary update result dicts
     """
@@ -569,14 +826,24 @@ def _apply_secondary_updates(
         sec_hs.update(sec_validated, weight=secondary_weight)
         sec_h_after = sec_hs.entropy()
         sec_jsd = compute_jsd(sec_posterior_before, sec_hs.posterior)
+
+        pending_journal.append(
+            {
+                "kind": "secondary",
+                "dimension": sec_dim,
+                "likelihood_source": "explicit",
+                "prior": sec_posterior_before,
+                "posterior": sec_hs.posterior,
+                "question_id": question_id(args.dimension, args.question),
+                "question_index": len(orch.question_history),
+                "likelihoods": {h: round(p, 4) for h, p in sec_validated.items()},
+                "weight": secondary_weight,
+            }
+        )
         sec_ig = sec_h_before - sec_h_after
 
         # Update cached values
-        sec_h_max = math.log2(len(sec_hs.hypotheses))
-        sec_hs._cached_entropy = sec_h_after
-        sec_hs._cached_confidence = (
-            1.0 - (sec_h_after / sec_h_max) if sec_h_max > 0 else 1.0
-        )
+        cache_uncertainty(sec_hs, sec_h_after)
 
         secondary_updates.append(
             {
@@ -592,6 +859,70 @@ def _apply_secondary_updates(
     return secondary_updates
 
 
+def parse_provided_likelihoods(
+    args: argparse.Namespace, hs: HypothesisSet
+) -> dict[str, float] | None:
+    """Parse --likelihoods (object or multi-select array), exiting on bad JSON."""
+    if args.likelihoods is None:
+        return None
+
+    try:
+        likelihoods_raw = json.loads(args.likelihoods)
+    except json.JSONDecodeError as e:
+        print(
+            json.dumps(
+                {"error": f"Invalid JSON in --likelihoods: {e}"},
+                ensure_ascii=False,
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    # Detect multi-select batch (JSON array) vs single-select (JSON object)
+    if isinstance(likelihoods_raw, list):
+        return compute_joint_likelihoods(hs, likelihoods_raw)
+    return validate_and_normalize_likelihoods(hs, likelihoods_raw)
+
+
+def build_evaluation_scores(
+    args: argparse.Namespace, confidence: float
+) -> dict[str, Any] | None:
+    """Evaluation scores for the history entry, if --reward was given."""
+    if args.reward is None:
+        return None
+
+    eig = max(0.0, args.eig) if args.eig is not None else 0.0
+    return {
+        "total_reward": args.reward,
+        "components": {
+            "info_gain": eig,
+            "clarity": args.clarity if args.clarity is not None else 0.0,
+            "importance": args.importance if args.importance is not None else 0.0,
+        },
+        "confidence": confidence,
+    }
+
+
+def credit_strategy(
+    orch: SessionOrchestrator,
+    args: argparse.Namespace,
+    info_gain: float,
+    history_entry: dict[str, Any],
+) -> float | None:
+    """Credit the strategy that selected this question (bits per token)."""
+    if orch.pending_strategy is None:
+        return None
+
+    tokens = estimate_tokens(args.question, args.answer)
+    strategy_reward = orch.manager.record_strategy_outcome(
+        orch.pending_strategy, info_gain, tokens
+    )
+    history_entry["strategy"] = orch.pending_strategy
+    history_entry["strategy_reward"] = strategy_reward
+    orch.pending_strategy = None
+    return strategy_reward
+
+
 def cmd_update_with_computation(args: argparse.Namespace) -> None:
     """Update beliefs with complete computation chain (Phase B + C).
 
@@ -604,28 +935,23 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
     information gain that sequential independent updates can produce.
     """
     orch = load_session_state(args.session_id)
+    hs = orch.beliefs[args.dimension]
 
-    # Parse likelihoods from LLM
+    # Parse likelihoods from LLM (optional for text-based likelihood models)
+    provided_likelihoods = parse_provided_likelihoods(args, hs)
+
+    # Phase B: Computation chain (Python)
     try:
-        likelihoods_raw = json.loads(args.likelihoods)
-    except json.JSONDecodeError as e:
-        print(
-            json.dumps(
-                {"error": f"Invalid JSON in --likelihoods: {e}"}, ensure_ascii=False
-            ),
-            file=sys.stderr,
+        model = model_for_dimension(
+            orch.config, args.dimension, args.likelihood_model
+        )
+        validated_likelihoods = model.likelihoods(
+            hs, args.answer, provided_likelihoods
         )
+    except ValueError as e:
+        print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
         sys.exit(1)
 
-    # Phase B: Computation chain (Python)
-    hs = orch.beliefs[args.dimension]
-
-    # Detect multi-select batch (JSON array) vs single-select (JSON object)
-    if isinstance(likelihoods_raw, list):
-        validated_likelihoods = compute_joint_likelihoods(hs, likelihoods_raw)
-    else:
-        validated_likelihoods = validate_and_normalize_likelihoods(hs, likelihoods_raw)
-
     # B1: Entropy before + capture pre-update posterior for JSD
     h_before = hs.entropy()
     posterior_before = dict(hs.posterior)
@@ -640,24 +966,27 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
     # B4: Information gain
     info_gain = h_before - h_after
 
+    # Journal entries are appended only after the state is saved
+    pending_journal: list[dict[str, Any]] = [
+        {
+            "kind": "answer",
+            "dimension": args.dimension,
+            "likelihood_source": model.name,
+            "prior": posterior_before,
+            "posterior": hs.posterior,
+            "question_id": question_id(args.dimension, args.question),
+            "question_index": len(orch.question_history),
+            "question": args.question,
+            "answer": args.answer,
+            "likelihoods": {h: round(p, 4) for h, p in validated_likelihoods.items()},
+        }
+    ]
+
     # Cache results for session_orchestrator methods
-    h_max = math.log2(len(hs.hypotheses))
-    hs._cached_entropy = h_after
-    hs._cached_confidence = 1.0 - (h_after / h_max) if h_max > 0 else 1.0
+    cache_uncertainty(hs, h_after)
 
     # Build evaluation scores if provided
-    evaluation_scores: dict[str, Any] | None = None
-    if args.reward is not None:
-        eig = max(0.0, args.eig) if args.eig is not None else 0.0
-        evaluation_scores = {
-            "total_reward": args.reward,
-            "components": {
-                "info_gain": eig,
-                "clarity": args.clarity if args.clarity is not None else 0.0,
-                "importance": args.importance if args.importance is not None else 0.0,
-            },
-            "confidence": hs._cached_confidence,
-        }
+    evaluation_scores = build_evaluation_scores(args, hs._cached_confidence)
 
     # Phase C: Persist to session history
     history_entry: dict[str, Any] = {
@@ -668,15 +997,20 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
         "entropy_after": h_after,
         "information_gain": info_gain,
         "jsd": jsd,
+        "likelihood_model": model.name,
+        "likelihoods": {h: round(p, 4) for h, p in validated_likelihoods.items()},
     }
     if evaluation_scores is not None:
         history_entry["evaluation_scores"] = evaluation_scores
 
     # Cross-dimension secondary updates (P2.5)
-    secondary_updates = _apply_secondary_updates(args, orch)
+    secondary_updates = _apply_secondary_updates(args, orch, pending_journal)
     if secondary_updates:
         history_entry["secondary_updates"] = secondary_updates
 
+    # Credit the strategy that selected this question (bits per token)
+    strategy_reward = credit_strategy(orch, args, info_gain, history_entry)
+
     orch.question_history.append(history_entry)
     orch.question_count += 1
 
@@ -684,16 +1018,15 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
     orch.update_thompson_state(args.dimension, info_gain)
     orch.record_information_gain(info_gain)
 
-    # Save updated state
+    # Save updated state, then journal what was persisted
     save_session_state(args.session_id, orch)
+    journal_seq = append_journal(args.session_id, pending_journal)
 
     # BALD decomposition for updated dimension
     decomp = hs.uncertainty_decomposition()
 
     # Output results
-    compact = getattr(args, "compact", False)
-
-    if compact:
+    if getattr(args, "compact", False):
         output: dict[str, Any] = {
             "status": "updated",
             "information_gain": round(info_gain, 4),
@@ -708,12 +1041,17 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
             "entropy_after": round(h_after, 4),
             "information_gain": round(info_gain, 4),
             "jsd": round(jsd, 4),
+            "likelihood_model": model.name,
+            "journal_seq": journal_seq,
             "question_count": orch.question_count,
             "epistemic_entropy": round(decomp["epistemic"], 4),
             "aleatoric_entropy": round(decomp["aleatoric"], 4),
         }
         if evaluation_scores is not None:
             output["evaluation_scores"] = evaluation_scores
+        if strategy_reward is not None:
+            output["strategy"] = history_entry["strategy"]
+            output["strategy_reward"] = round(strategy_reward, 4)
         if secondary_updates:
             total_cross_dim_ig = sum(u["information_gain"] for u in secondary_updates)
             output["secondary_updates"] = secondary_updates
@@ -721,17 +1059,704 @@ def cmd_update_with_computation(args: argparse.Namespace) -> None:
     print(json.dumps(output, ensure_ascii=False))
 
 
-def main() -> None:
-    """CLI entry point."""
-    parser = argparse.ArgumentParser(description="Good Question session management CLI")
-    subparsers = parser.add_subparsers(dest="command", required=True)
+def cmd_resolve_contradiction(args: argparse.Namespace) -> None:
+    """Resolve a cross-dimension contradiction flagged by the presheaf checker.
+
+    Without --endorse, lists the current contradictions so Claude can ask the
+    user which belief holds. With --edge and --endorse, applies a dampened
+    corrective update to the other side and records the resolution.
+    """
+    orch = load_session_state(args.session_id)
+    checker = orch.presheaf_checker
+
+    if args.endorse is None:
+        contradictions = checker.describe_contradictions(orch.beliefs)
+        if args.edge is not None:
+            contradictions = [c for c in contradictions if c["edge"] == args.edge]
+        print(
+            json.dumps(
+                {
+                    "contradictions": contradictions,
+                    "count": len(contradictions),
+                },
+                ensure_ascii=False,
+            )
+        )
+        return
+
+    if args.edge is None or "->" not in args.edge:
+        print(
+            json.dumps(
+                {"error": "--edge must be given as 'source->target' with --endorse"},
+                ensure_ascii=False,
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    source, target = (part.strip() for part in args.edge.split("->", 1))
+    dampening = orch.config["session_config"].get("contradiction_dampening", 0.5)
+    priors = {
+        d: dict(orch.beliefs[d].posterior)
+        for d in (source, target)
+        if d in orch.beliefs
+    }
+
+    try:
+        resolution = checker.resolve_contradiction(
+            orch.beliefs, source, target, args.endorse, dampening=dampening
+        )
+    except ValueError as e:
+        print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    # Update cached values for the corrected dimension
+    hs = orch.beliefs[resolution["corrected_dimension"]]
+    cache_uncertainty(hs, hs.entropy())
+
+    if args.question is not None:
+        resolution["question"] = args.question
+    if args.answer is not None:
+        resolution["answer"] = args.answer
+    resolution["question_index"] = orch.question_count
+
+    corrected = resolution["corrected_dimension"]
+    provenance: dict[str, Any] = {
+        "question_index": orch.question_count,
+        "endorsed": args.endorse,
+        "weight": resolution["weight"],
+    }
+    if args.question is not None:
+        provenance["question"] = args.question
+    if args.answer is not None:
+        provenance["answer"] = args.answer
+    orch.contradiction_resolutions.append(resolution)
+    save_session_state(args.session_id, orch)
+    append_entry(
+        get_journal_file(args.session_id),
+        "contradiction_resolution",
+        corrected,
+        f"presheaf:{resolution['edge']}",
+        priors[corrected],
+        hs.posterior,
+        **provenance,
+    )
+
+    print(json.dumps({"status": "resolved", **resolution}, ensure_ascii=False))
+
+
+def cmd_set_archetype(args: argparse.Namespace) -> None:
+    """Re-seed beliefs with a project archetype prior.
+
+    With --archetype, applies the given archetype. With --infer, ranks
+    archetypes against the answers so far and applies the best one only if
+    its probability reaches the configured min_probability.
+    """
+    orch = load_session_state(args.session_id)
+    library = load_archetypes()
+    archetypes = library["archetypes"]
+    inference = library.get("inference", {})
+
+    output: dict[str, Any] = {"previous_archetype": orch.archetype}
+    archetype = args.archetype
+
+    if args.infer:
+        ranking = infer_archetype(
+            orch.beliefs,
+            archetypes,
+            current=orch.archetype,
+            min_observations=inference.get("min_observations", 1.0),
+        )
+        output["ranking"] = ranking
+        min_probability = inference.get("min_probability", 0.6)
+        if not ranking or ranking[0]["probability"] < min_probability:
+            output["status"] = "undecided"
+            print(json.dumps(output, ensure_ascii=False))
+            return
+        archetype = ranking[0]["archetype"]
+
+    if archetype == orch.archetype:
+        output["status"] = "unchanged"
+        output["archetype"] = archetype
+        print(json.dumps(output, ensure_ascii=False))
+        return
+
+    priors = {k: dict(v.posterior) for k, v in orch.beliefs.items()}
+    try:
+        reseeded = apply_archetype(
+            orch.beliefs, archetypes, archetype, previous=orch.archetype
+        )
+    except ValueError as e:
+        print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    orch.archetype = archetype
+    save_session_state(args.session_id, orch)
+    journal_archetype(args.session_id, orch, archetype, reseeded, priors)
+
+    output["status"] = "applied"
+    output["archetype"] = archetype
+    output["reseeded_dimensions"] = reseeded
+    print(json.dumps(output, ensure_ascii=False))
+
+
+def cmd_coupling(args: argparse.Namespace) -> None:
+    """Output mutual-information coupling matrix between adjacent dimensions."""
+    orch = load_session_state(args.session_id)
+    threshold = orch.config["session_config"].get("coupling_batch_threshold", 0.1)
+
+    print(
+        json.dumps(
+            {
+                "session_id": args.session_id,
+                "coupling_batch_threshold": threshold,
+                "edges": orch.presheaf_checker.coupling_matrix(orch.beliefs),
+            },
+            indent=2,
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_search_questions(args: argparse.Namespace) -> None:
+    """Search the question bank, optionally limited to a session's DAG frontier."""
+    bank = load_bank(get_question_bank_paths())
+    frontier = None
+
+    if args.session_id is not None:
+        orch = load_session_state(args.session_id)
+        asked = {
+            question_id(h["dimension"], h["question"]) for h in orch.question_history
+        }
+        bank = [q for q in bank if q.id not in asked]
+        if not args.all_dimensions:
+            frontier = orch.get_frontier()
+
+    tags = [t.strip() for t in args.tags.split(",")] if args.tags else None
+    results = search_questions(
+        bank,
+        query=args.query,
+        dimension=args.dimension,
+        tags=tags,
+        frontier=frontier,
+        limit=args.limit,
+    )
+
+    print(
+        json.dumps(
+            {
+                "frontier": frontier,
+                "count": len(results),
+                "questions": [q.to_dict() for q in results],
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_add_question(args: argparse.Namespace) -> None:
+    """Add a question with likelihood templates to the workspace question bank."""
+    orch = SessionOrchestrator()
+    dim_config = orch.config["dimensions"].get(args.dimension)
+    if dim_config is None:
+        print(
+            json.dumps(
+                {"error": f"Unknown dimension: {args.dimension}"}, ensure_ascii=False
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    try:
+        templates = json.loads(args.likelihood_templates or "{}")
+    except json.JSONDecodeError as e:
+        print(
+            json.dumps(
+                {"error": f"Invalid JSON in --likelihood-templates: {e}"},
+                ensure_ascii=False,
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    question = BankQuestion(
+        text=args.question,
+        dimension=args.dimension,
+        tags=[t.strip() for t in args.tags.split(",")] if args.tags else [],
+        likelihood_templates=templates,
+    )
+
+    errors = validate_templates(question, dim_config)
+    if errors:
+        print(json.dumps({"error": errors}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    bank_file = get_session_dir().parent / "question_bank.json"
+    try:
+        added = add_question(bank_file, question)
+    except (json.JSONDecodeError, ValueError) as e:
+        error = {"error": f"Could not update question bank {bank_file}: {e}"}
+        print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    print(
+        json.dumps(
+            {
+                "status": "added" if added else "duplicate",
+                "id": question.id,
+                "dimension": question.dimension,
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_fork_session(args: argparse.Namespace) -> None:
+    """Branch a session to explore an alternative answer path."""
+    orch = load_session_state(args.session_id)
+    fork_id = orch.fork_session()
+    save_session_state(fork_id, orch)
+
+    # The fork inherits the parent's journal up to the branch point
+    parent_journal = get_journal_file(args.session_id)
+    if parent_journal.exists():
+        shutil.copyfile(parent_journal, get_journal_file(fork_id))
+
+    print(
+        json.dumps(
+            {
+                "session_id": fork_id,
+                "status": "forked",
+                "forked_from": orch.forked_from,
+                "question_count": orch.question_count,
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_diff_sessions(args: argparse.Namespace) -> None:
+    """Report how far two sessions' beliefs have diverged."""
+    orch_a = load_session_state(args.session_id)
+    orch_b = load_session_state(args.other_session_id)
+
+    # Shared prefix of question/answer pairs = point where the paths split
+    common = 0
+    for entry_a, entry_b in zip(
+        orch_a.question_history, orch_b.question_history, strict=False
+    ):
+        if (entry_a["question"], entry_a["answer"]) != (
+            entry_b["question"],
+            entry_b["answer"],
+        ):
+            break
+        common += 1
+
+    dimensions = diff_beliefs(orch_a.beliefs, orch_b.beliefs)
+    diverged = [d for d in dimensions if d["jsd"] >= args.threshold]
+
+    print(
+        json.dumps(
+            {
+                "session_a": args.session_id,
+                "session_b": args.other_session_id,
+                "common_questions": common,
+                "question_count_a": orch_a.question_count,
+                "question_count_b": orch_b.question_count,
+                "threshold": args.threshold,
+                "diverged_dimensions": [d["dimension"] for d in diverged],
+                "most_likely_changed": [
+                    d["dimension"] for d in dimensions if d["most_likely_changed"]
+                ],
+                "dimensions": dimensions,
+            },
+            indent=2,
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_draft(args: argparse.Namespace) -> None:
+    """Render the session's beliefs as a requirements document draft."""
+    orch = load_session_state(args.session_id)
+    draft_file = write_draft(args.session_id, orch)
+
+    open_threshold = orch.config["session_config"].get(
+        "draft_open_entropy_threshold", 1.0
+    )
+    open_dimensions = [
+        dim_id
+        for dim_id, hs in orch.beliefs.items()
+        if (
+            hs._cached_entropy
+            if hs._cached_entropy is not None
+            else math.log2(len(hs.hypotheses))
+        )
+        > open_threshold
+    ]
+
+    print(
+        json.dumps(
+            {
+                "session_id": args.session_id,
+                "path": str(draft_file),
+                "open_dimensions": open_dimensions,
+                "markdown": draft_file.read_text(encoding="utf-8"),
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_understanding(args: argparse.Namespace) -> None:
+    """Output a compact current-understanding block for prompt injection."""
+    orch = load_session_state(args.session_id)
+
+    max_tokens = args.max_tokens
+    if max_tokens is None:
+        max_tokens = orch.config["session_config"].get("understanding_max_tokens", 150)
+
+    result = render_understanding(orch, max_tokens)
+    result["session_id"] = args.session_id
+    result["max_tokens"] = max_tokens
+    print(json.dumps(result, ensure_ascii=False))
+
+
+def cmd_journal(args: argparse.Namespace) -> None:
+    """Read a range of the session's observation journal."""
+    journal_file = get_journal_file(args.session_id)
+    if not (get_session_dir() / f"{args.session_id}.json").exists():
+        print(
+            json.dumps(
+                {"error": f"Session not found: {args.session_id}"}, ensure_ascii=False
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    entries = read_entries(journal_file, args.start, args.end, args.dimension)
+
+    print(
+        json.dumps(
+            {
+                "session_id": args.session_id,
+                "count": len(entries),
+                "entries": entries,
+            },
+            indent=2,
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_export_session(args: argparse.Namespace) -> None:
+    """Export a session and its journal as a versioned snapshot."""
+    session_file = get_session_dir() / f"{args.session_id}.json"
+    if not session_file.exists():
+        print(
+            json.dumps(
+                {"error": f"Session not found: {args.session_id}"}, ensure_ascii=False
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    with open(session_file, encoding="utf-8") as f:
+        state = json.load(f)
+
+    try:
+        snapshot = export_snapshot(
+            state, read_entries(get_journal_file(args.session_id))
+        )
+    except ValueError as e:
+        print(json.dumps({"error": str(e)}, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    if args.output is None:
+        print(json.dumps(snapshot, indent=2, ensure_ascii=False))
+        return
+
+    output_file = Path(args.output)
+    output_file.parent.mkdir(parents=True, exist_ok=True)
+    with open(output_file, "w", encoding="utf-8") as f:
+        json.dump(snapshot, f, indent=2, ensure_ascii=False)
+
+    print(
+        json.dumps(
+            {
+                "session_id": args.session_id,
+                "status": "exported",
+                "path": str(output_file),
+                "schema_version": SCHEMA_VERSION,
+                "journal_entries": len(snapshot["journal"]),
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def cmd_import_session(args: argparse.Namespace) -> None:
+    """Import a snapshot (or bare session file), migrating it to the schema.
+
+    The migrated state is written as-is, so completion fields survive a
+    round trip and a completed session cannot be completed again.
+
+    Examples:
+        >>> import contextlib, io, os, shutil, tempfile
+        >>> workspace = tempfile.mkdtemp()
+        >>> saved_cwd, saved_pwd = os.getcwd(), os.environ.get("PWD", "")
+        >>> os.chdir(workspace)
+        >>> os.environ["PWD"] = workspace
+        >>> (Path(workspace) / ".claude").mkdir()
+        >>> with contextlib.redirect_stdout(io.StringIO()) as out:
+        ...     cmd_init(argparse.Namespace(archetype=None))
+        >>> sid = json.loads(out.getvalue())["session_id"]
+        >>> with contextlib.redirect_stdout(io.StringIO()):
+        ...     cmd_complete(argparse.Namespace(session_id=sid, confirmed=None))
+        ...     cmd_export_session(
+        ...         argparse.Namespace(session_id=sid, output="snapshot.json")
+        ...     )
+        ...     cmd_import_session(
+        ...         argparse.Namespace(input="snapshot.json", overwrite=True)
+        ...     )
+        >>> with contextlib.redirect_stderr(io.StringIO()) as err:
+        ...     try:
+        ...         cmd_complete(argparse.Namespace(session_id=sid, confirmed=None))
+        ...     except SystemExit as e:
+        ...         print(e.code, err.getvalue().strip())
+        1 {"error": "Session already completed: ..."}
+        >>> os.chdir(saved_cwd)
+        >>> os.environ["PWD"] = saved_pwd
+        >>> shutil.rmtree(workspace)
+    """
+    try:
+        with open(args.input, encoding="utf-8") as f:
+            data = json.load(f)
+        state, journal = import_snapshot(data)
+    except (OSError, json.JSONDecodeError, ValueError) as e:
+        error = {"error": f"Cannot import {args.input}: {e}"}
+        print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+        sys.exit(1)
+
+    session_id = state["session_id"]
+    session_file = get_session_dir() / f"{session_id}.json"
+
+    # Defense in depth: the ID pattern already excludes path separators
+    workspace_dir = get_session_dir().parent
+    targets = [
+        (session_file, get_session_dir()),
+        (get_journal_file(session_id), workspace_dir / "journal"),
+        (get_draft_file(session_id), workspace_dir / "drafts"),
+    ]
+    for path, base in targets:
+        if not path.resolve().is_relative_to(base.resolve()):
+            error = {"error": f"Refusing to write outside {base}: {path}"}
+            print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+            sys.exit(1)
+
+    if session_file.exists() and not args.overwrite:
+        print(
+            json.dumps(
+                {"error": f"Session already exists: {session_id} (use --overwrite)"},
+                ensure_ascii=False,
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    with open(session_file, "w", encoding="utf-8") as f:
+        json.dump(state, f, indent=2, ensure_ascii=False)
+    restore_journal(get_journal_file(session_id), journal)
+
+    # Regenerate derived files (draft) without re-saving the state
+    write_draft(session_id, restore_orchestrator(state))
+
+    print(
+        json.dumps(
+            {
+                "session_id": session_id,
+                "status": "imported",
+                "schema_version": state["schema_version"],
+                "question_count": state["question_count"],
+                "journal_entries": len(journal),
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def purge_session_files(session_id: str) -> tuple[list[str], list[str]]:
+    """Delete the state, journal and draft of a session.
+
+    Returns the removed paths and any paths that still exist afterwards.
+    """
+    files = [
+        get_session_dir() / f"{session_id}.json",
+        get_journal_file(session_id),
+        get_draft_file(session_id),
+    ]
+    removed = []
+    for path in files:
+        if path.exists():
+            path.unlink()
+            removed.append(str(path))
+    return removed, [str(path) for path in files if path.exists()]
+
+
+def cmd_purge(args: argparse.Namespace) -> None:
+    """Delete everything stored for one session, or for sessions older than N days.
+
+    Removes the session state, journal, requirements draft and the question
+    feedback records linked to the session. With --older-than-days, feedback
+    records started before the cutoff are removed by their own timestamp as
+    well. The report lists any file or feedback record left behind so the
+    caller can verify the purge.
+
+    Examples:
+        >>> import os, tempfile
+        >>> from contextlib import redirect_stdout
+        >>> from io import StringIO
+        >>> cwd, pwd = os.getcwd(), os.environ.get("PWD")
+        >>> os.chdir(tempfile.mkdtemp())
+        >>> os.environ["PWD"] = os.getcwd()
+        >>> with redirect_stdout(StringIO()) as out:
+        ...     cmd_init(argparse.Namespace(archetype=None))
+        >>> sid = json.loads(out.getvalue())["session_id"]
+        >>> manager = QuestionFeedbackManager()
+        >>> linked = manager.start_session(with_me_session_id=sid)
+        >>> bare = manager.start_session()
+        >>> with redirect_stdout(StringIO()) as out:
+        ...     cmd_purge(argparse.Namespace(session_id=sid, older_than_days=None))
+        >>> report = json.loads(out.getvalue())
+        >>> sorted(report["removed_feedback_records"]) == sorted([sid, linked])
+        True
+        >>> report["remaining_files"], report["remaining_feedback_records"]
+        ([], [])
+        >>> report["unlinked_feedback_records"] == [bare]
+        True
+        >>> with redirect_stdout(StringIO()) as out:
+        ...     cmd_purge(argparse.Namespace(session_id=None, older_than_days=0))
+        >>> json.loads(out.getvalue())["removed_feedback_records"] == [bare]
+        True
+        >>> os.chdir(cwd)
+        >>> if pwd is not None:
+        ...     os.environ["PWD"] = pwd
+    """
+    if args.session_id is not None:
+        if not is_valid_session_id(args.session_id):
+            error = {"error": f"Invalid session_id: {args.session_id!r}"}
+            print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+            sys.exit(1)
+        session_ids = [args.session_id]
+        started_before = None
+    else:
+        if args.older_than_days < 0:
+            error = {"error": "--older-than-days must be non-negative"}
+            print(json.dumps(error, ensure_ascii=False), file=sys.stderr)
+            sys.exit(1)
+        cutoff = datetime.now(UTC) - timedelta(days=args.older_than_days)
+        session_ids = sorted(
+            f.stem
+            for f in get_session_dir().glob("*.json")
+            if datetime.fromtimestamp(f.stat().st_mtime, UTC) < cutoff
+        )
+        # Feedback records store naive local timestamps
+        started_before = cutoff.astimezone().replace(tzinfo=None)
+
+    removed: list[str] = []
+    purged: list[str] = []
+    remaining: list[str] = []
+    for session_id in session_ids:
+        removed_files, remaining_files = purge_session_files(session_id)
+        removed.extend(removed_files)
+        remaining.extend(remaining_files)
+        if removed_files:
+            purged.append(session_id)
+
+    manager = QuestionFeedbackManager()
+    feedback_links = {
+        s["session_id"]: s.get("with_me_session_id", s["session_id"])
+        for s in manager.data["sessions"]
+    }
+    removed_records = manager.remove_sessions(set(session_ids), started_before)
+    purged.extend(
+        session_id
+        for session_id in session_ids
+        if session_id not in purged
+        and any(feedback_links[r] == session_id for r in removed_records)
+    )
+
+    if args.session_id is not None and not purged:
+        print(
+            json.dumps(
+                {"error": f"Session not found: {args.session_id}"}, ensure_ascii=False
+            ),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    known_sessions = {f.stem for f in get_session_dir().glob("*.json")}
+    print(
+        json.dumps(
+            {
+                "status": "purged",
+                "sessions": purged,
+                "removed_files": removed,
+                "removed_feedback_records": removed_records,
+                "remaining_files": remaining,
+                "remaining_feedback_records": manager.find_sessions(
+                    set(session_ids), started_before
+                ),
+                # Records from a bare `feedback start` may still hold answers
+                "unlinked_feedback_records": manager.find_unlinked_sessions(
+                    known_sessions
+                ),
+            },
+            ensure_ascii=False,
+        )
+    )
+
+
+def positive_int(value: str) -> int:
+    """Argparse type for integers greater than zero.
+
+    Examples:
+        >>> positive_int("150")
+        150
+        >>> positive_int("0")
+        Traceback (most recent call last):
+        ...
+        argparse.ArgumentTypeError: must be a positive integer, got '0'
+    """
+    try:
+        number = int(value)
+    except ValueError:
+        number = 0
+    if number <= 0:
+        msg = f"must be a positive integer, got {value!r}"
+        raise argparse.ArgumentTypeError(msg)
+    return number
+
 
+def add_session_parsers(subparsers: argparse._SubParsersAction) -> None:
+    """Register the core interview commands."""
     # init command
-    subparsers.add_parser("init", help="Initialize new session")
+    init_parser = subparsers.add_parser("init", help="Initialize new session")
+    init_parser.add_argument(
+        "--archetype",
+        default=None,
+        help="Seed beliefs with a project archetype prior (e.g., cli_tool)",
+    )
 
     # next-question command
     next_parser = subparsers.add_parser("next-question", help="Get next question")
     next_parser.add_argument("--session-id", required=True, help="Session ID")
+    next_parser.add_argument(
+        "--strategy",
+        choices=STRATEGIES,
+        default=None,
+        help="Question-selection strategy (default: chosen by the bandit)",
+    )
 
     # status command
     status_parser = subparsers.add_parser("status", help="Display session state")
@@ -746,6 +1771,12 @@ def main() -> None:
     # complete command
     complete_parser = subparsers.add_parser("complete", help="Complete session")
     complete_parser.add_argument("--session-id", required=True, help="Session ID")
+    complete_parser.add_argument(
+        "--confirmed",
+        default=None,
+        help="User-confirmed hypotheses as JSON {dimension: hypothesis}, "
+        "used to score likelihood model calibration",
+    )
 
     # evaluate-question command
     eval_parser = subparsers.add_parser(
@@ -768,13 +1799,20 @@ def main() -> None:
     update_comp_parser.add_argument(
         "--likelihoods",
         type=str,
-        required=True,
+        default=None,
         help=(
-            "Likelihoods as JSON object or array. "
+            "Likelihoods as JSON object or array "
+            "(required by the explicit likelihood model). "
             "Single: '{\"h1\": 0.5, ...}'. "
             'Multi-select: \'[{"h1": 0.8, ...}, {"h1": 0.3, ...}]\''
         ),
     )
+    update_comp_parser.add_argument(
+        "--likelihood-model",
+        choices=LIKELIHOOD_MODELS,
+        default=None,
+        help="Likelihood model (default: configured for the dimension)",
+    )
     update_comp_parser.add_argument(
         "--reward",
         type=float,
@@ -818,21 +1856,229 @@ def main() -> None:
         help="Output only status, information_gain, question_count, converged",
     )
 
+
+def add_belief_parsers(subparsers: argparse._SubParsersAction) -> None:
+    """Register commands that inspect or reshape beliefs."""
+    # resolve-contradiction command
+    resolve_parser = subparsers.add_parser(
+        "resolve-contradiction",
+        help="List contradictions, or resolve one with the user's endorsement",
+    )
+    resolve_parser.add_argument("--session-id", required=True, help="Session ID")
+    resolve_parser.add_argument(
+        "--edge",
+        type=str,
+        default=None,
+        help="Inconsistent edge as 'source->target' (e.g., 'purpose->data')",
+    )
+    resolve_parser.add_argument(
+        "--endorse",
+        choices=["source", "target"],
+        default=None,
+        help="Side of the edge the user endorses; omit to list contradictions",
+    )
+    resolve_parser.add_argument(
+        "--question", default=None, help="Clarifying question asked (optional)"
+    )
+    resolve_parser.add_argument(
+        "--answer", default=None, help="User's answer (optional)"
+    )
+
+    # set-archetype command
+    archetype_parser = subparsers.add_parser(
+        "set-archetype", help="Re-seed beliefs with a project archetype prior"
+    )
+    archetype_parser.add_argument("--session-id", required=True, help="Session ID")
+    archetype_group = archetype_parser.add_mutually_exclusive_group(required=True)
+    archetype_group.add_argument(
+        "--archetype", default=None, help="Archetype ID (e.g., web_service)"
+    )
+    archetype_group.add_argument(
+        "--infer",
+        action="store_true",
+        default=False,
+        help="Infer the archetype from the answers so far",
+    )
+
+    # coupling command
+    coupling_parser = subparsers.add_parser(
+        "coupling", help="Output mutual-information coupling between dimensions"
+    )
+    coupling_parser.add_argument("--session-id", required=True, help="Session ID")
+
+    # fork-session command
+    fork_parser = subparsers.add_parser(
+        "fork-session", help="Branch a session for what-if exploration"
+    )
+    fork_parser.add_argument("--session-id", required=True, help="Session ID")
+
+    # diff-sessions command
+    diff_parser = subparsers.add_parser(
+        "diff-sessions", help="Compare beliefs of two sessions"
+    )
+    diff_parser.add_argument("--session-id", required=True, help="First session ID")
+    diff_parser.add_argument(
+        "--other-session-id", required=True, help="Second session ID"
+    )
+    diff_parser.add_argument(
+        "--threshold",
+        type=float,
+        default=0.05,
+        help="Minimum JSD for a dimension to count as diverged",
+    )
+
+
+def add_question_bank_parsers(subparsers: argparse._SubParsersAction) -> None:
+    """Register question bank commands."""
+    # search-questions command
+    search_parser = subparsers.add_parser(
+        "search-questions", help="Search the question bank"
+    )
+    search_parser.add_argument(
+        "--session-id",
+        default=None,
+        help="Limit to the session's DAG frontier and skip asked questions",
+    )
+    search_parser.add_argument("--query", default=None, help="Free-text query")
+    search_parser.add_argument("--dimension", default=None, help="Dimension ID")
+    search_parser.add_argument(
+        "--tags", default=None, help="Comma-separated tags (all must match)"
+    )
+    search_parser.add_argument(
+        "--all-dimensions",
+        action="store_true",
+        default=False,
+        help="Do not restrict results to the DAG frontier",
+    )
+    search_parser.add_argument(
+        "--limit", type=int, default=5, help="Maximum number of results"
+    )
+
+    # add-question command
+    add_q_parser = subparsers.add_parser(
+        "add-question", help="Add a question to the workspace question bank"
+    )
+    add_q_parser.add_argument("--dimension", required=True, help="Dimension ID")
+    add_q_parser.add_argument("--question", required=True, help="Question text")
+    add_q_parser.add_argument("--tags", default=None, help="Comma-separated tags")
+    add_q_parser.add_argument(
+        "--likelihood-templates",
+        default=None,
+        help='Answer templates as JSON: {"answer": {"hyp": prob}}',
+    )
+
+
+def add_output_parsers(subparsers: argparse._SubParsersAction) -> None:
+    """Register commands that render session state."""
+    # draft command
+    draft_parser = subparsers.add_parser(
+        "draft", help="Render beliefs as a requirements document draft"
+    )
+    draft_parser.add_argument("--session-id", required=True, help="Session ID")
+
+    # understanding command
+    understanding_parser = subparsers.add_parser(
+        "understanding", help="Compact current-understanding block"
+    )
+    understanding_parser.add_argument(
+        "--session-id", required=True, help="Session ID"
+    )
+    understanding_parser.add_argument(
+        "--max-tokens",
+        type=positive_int,
+        default=None,
+        help="Token budget (default: session_config.understanding_max_tokens)",
+    )
+
+    # journal command
+    journal_parser = subparsers.add_parser(
+        "journal", help="Read belief updates with provenance"
+    )
+    journal_parser.add_argument("--session-id", required=True, help="Session ID")
+    journal_parser.add_argument(
+        "--start", type=int, default=0, help="First sequence number (inclusive)"
+    )
+    journal_parser.add_argument(
+        "--end", type=int, default=None, help="Last sequence number (exclusive)"
+    )
+    journal_parser.add_argument(
+        "--dimension", default=None, help="Only entries for this dimension"
+    )
+
+
+def add_snapshot_parsers(subparsers: argparse._SubParsersAction) -> None:
+    """Register commands that move or delete session data."""
+    # export-session command
+    export_parser = subparsers.add_parser(
+        "export-session", help="Export a session snapshot"
+    )
+    export_parser.add_argument("--session-id", required=True, help="Session ID")
+    export_parser.add_argument(
+        "--output", default=None, help="Snapshot file (default: print to stdout)"
+    )
+
+    # import-session command
+    import_parser = subparsers.add_parser(
+        "import-session", help="Import a session snapshot or session file"
+    )
+    import_parser.add_argument("--input", required=True, help="Snapshot file")
+    import_parser.add_argument(
+        "--overwrite",
+        action="store_true",
+        default=False,
+        help="Replace an existing session with the same ID",
+    )
+
+    # purge command
+    purge_parser = subparsers.add_parser(
+        "purge", help="Delete stored data for a session or for old sessions"
+    )
+    purge_target = purge_parser.add_mutually_exclusive_group(required=True)
+    purge_target.add_argument("--session-id", help="Session ID")
+    purge_target.add_argument(
+        "--older-than-days",
+        type=float,
+        help="Purge sessions last saved more than this many days ago",
+    )
+
+
+COMMANDS: dict[str, Callable[[argparse.Namespace], None]] = {
+    "init": cmd_init,
+    "next-question": cmd_next_question,
+    "status": cmd_status,
+    "complete": cmd_complete,
+    "evaluate-question": cmd_evaluate_question,
+    "update-with-computation": cmd_update_with_computation,
+    "resolve-contradiction": cmd_resolve_contradiction,
+    "set-archetype": cmd_set_archetype,
+    "coupling": cmd_coupling,
+    "search-questions": cmd_search_questions,
+    "add-question": cmd_add_question,
+    "fork-session": cmd_fork_session,
+    "diff-sessions": cmd_diff_sessions,
+    "draft": cmd_draft,
+    "understanding": cmd_understanding,
+    "journal": cmd_journal,
+    "export-session": cmd_export_session,
+    "import-session": cmd_import_session,
+    "purge": cmd_purge,
+}
+
+
+def main() -> None:
+    """CLI entry point."""
+    parser = argparse.ArgumentParser(description="Good Question session management CLI")
+    subparsers = parser.add_subparsers(dest="command", required=True)
+    add_session_parsers(subparsers)
+    add_belief_parsers(subparsers)
+    add_question_bank_parsers(subparsers)
+    add_output_parsers(subparsers)
+    add_snapshot_parsers(subparsers)
+
     args = parser.parse_args()
 
     # Dispatch to command handler
-    if args.command == "init":
-        cmd_init(args)
-    elif args.command == "next-question":
-        cmd_next_question(args)
-    elif args.command == "status":
-        cmd_status(args)
-    elif args.command == "complete":
-        cmd_complete(args)
-    elif args.command == "evaluate-question":
-        cmd_evaluate_question(args)
-    elif args.command == "update-with-computation":
-        cmd_update_with_computation(args)
+    COMMANDS[args.command](args)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,266 @@
+#!/usr/bin/env python3
+"""
+Prior libraries per project archetype.
+
+Common project shapes (CLI tool, web service, data pipeline) have predictable
+answers on several dimensions. Seeding beliefs with an archetype's prior
+instead of a uniform one reduces the number of questions needed to converge.
+
+Responsibilities:
+- Load archetype priors from config/archetypes.json
+- Re-seed beliefs with an archetype prior, keeping accumulated evidence
+- Infer the most likely archetype from the first answers
+"""
+
+import doctest
+import json
+import math
+import sys
+from pathlib import Path
+from typing import Any
+
+from with_me.lib.dimension_belief import HypothesisSet
+
+
+def load_archetypes(config_path: Path | None = None) -> dict[str, Any]:
+    """
+    Load archetype prior library.
+
+    Args:
+        config_path: Path to archetypes.json. If None, uses the plugin default.
+
+    Returns:
+        Parsed config with "archetypes" and "inference" sections
+
+    Examples:
+        >>> config = load_archetypes()
+        >>> sorted(config["archetypes"])
+        ['cli_tool', 'data_pipeline', 'web_service']
+        >>> "min_probability" in config["inference"]
+        True
+    """
+    if config_path is None:
+        # Path: with_me/lib/archetype_priors.py → plugins/with-me/
+        plugin_root = Path(__file__).parent.parent.parent
+        config_path = plugin_root / "config" / "archetypes.json"
+
+    with open(config_path, encoding="utf-8") as f:
+        return json.load(f)
+
+
+def archetype_alpha(
+    archetypes: dict[str, Any], archetype: str | None, hs: HypothesisSet
+) -> dict[str, float]:
+    """
+    Get the prior Dirichlet parameters an archetype assigns to a dimension.
+
+    Uses the same conversion as HypothesisSet(prior=...): alpha[h] = p(h) * N,
+    so the prior carries as many pseudo-observations as the uniform prior.
+    Dimensions the archetype does not cover (or archetype=None) stay uniform.
+
+    Args:
+        archetypes: Archetype mapping from load_archetypes()["archetypes"]
+        archetype: Archetype ID, or None for the uniform prior
+        hs: Hypothesis set of the dimension
+
+    Returns:
+        Prior alpha per hypothesis
+
+    Examples:
+        >>> archetypes = {"cli": {"priors": {"purpose": {"a": 3.0, "b": 1.0}}}}
+        >>> hs = HypothesisSet("purpose", ["a", "b"])
+        >>> archetype_alpha(archetypes, "cli", hs)
+        {'a': 1.5, 'b': 0.5}
+
+        >>> # Uncovered dimension or no archetype → uniform
+        >>> archetype_alpha(archetypes, "cli", HypothesisSet("data", ["x", "y"]))
+        {'x': 1.0, 'y': 1.0}
+        >>> archetype_alpha(archetypes, None, hs)
+        {'a': 1.0, 'b': 1.0}
+    """
+    priors = archetypes.get(archetype, {}).get("priors", {}) if archetype else {}
+    prior = priors.get(hs.dimension)
+    if prior is None:
+        return {h: 1.0 for h in hs.hypotheses}
+
+    return HypothesisSet(hs.dimension, hs.hypotheses, prior=prior).alpha
+
+
+def apply_archetype(
+    beliefs: dict[str, HypothesisSet],
+    archetypes: dict[str, Any],
+    archetype: str,
+    previous: str | None = None,
+) -> list[str]:
+    """
+    Re-seed beliefs with an archetype prior, keeping accumulated evidence.
+
+    Evidence is whatever alpha has accumulated on top of the previous prior:
+    alpha_new[h] = alpha_archetype[h] + (alpha_old[h] - alpha_previous[h])
+
+    Args:
+        beliefs: Current belief state (modified in place)
+        archetypes: Archetype mapping from load_archetypes()["archetypes"]
+        archetype: Archetype ID to apply
+        previous: Archetype previously applied (None = uniform prior)
+
+    Returns:
+        Dimension IDs whose prior changed
+
+    Raises:
+        ValueError: If archetype is unknown
+
+    Examples:
+        >>> archetypes = {
+        ...     "cli": {"priors": {"purpose": {"a": 0.75, "b": 0.25}}},
+        ...     "web": {"priors": {"purpose": {"a": 0.25, "b": 0.75}}},
+        ... }
+        >>> beliefs = {"purpose": HypothesisSet("purpose", ["a", "b"])}
+        >>> beliefs["purpose"].update({"a": 1.0, "b": 0.0})
+        >>> apply_archetype(beliefs, archetypes, "cli")
+        ['purpose']
+        >>> beliefs["purpose"].alpha
+        {'a': 2.5, 'b': 0.5}
+
+        >>> # Switching archetype replaces the prior, not the evidence
+        >>> _ = apply_archetype(beliefs, archetypes, "web", previous="cli")
+        >>> beliefs["purpose"].alpha
+        {'a': 1.5, 'b': 1.5}
+
+        >>> apply_archetype(beliefs, archetypes, "unknown")
+        Traceback (most recent call last):
+        ...
+        ValueError: Unknown archetype: unknown
+    """
+    if archetype not in archetypes:
+        msg = f"Unknown archetype: {archetype}"
+        raise ValueError(msg)
+
+    reseeded: list[str] = []
+    for dim_id, hs in beliefs.items():
+        old_prior = archetype_alpha(archetypes, previous, hs)
+        new_prior = archetype_alpha(archetypes, archetype, hs)
+        if old_prior == new_prior:
+            continue
+
+        for h in hs.hypotheses:
+            evidence = hs.alpha[h] - old_prior[h]
+            # Floor keeps alpha positive if evidence was reduced elsewhere
+            hs.alpha[h] = max(HypothesisSet.EPSILON, new_prior[h] + evidence)
+        # Refresh cached values so prerequisites see the new prior
+        entropy = hs.entropy()
+        h_max = math.log2(len(hs.hypotheses))
+        hs._cached_entropy = entropy
+        hs._cached_confidence = 1.0 - (entropy / h_max) if h_max > 0 else 1.0
+        reseeded.append(dim_id)
+
+    return reseeded
+
+
+def infer_archetype(
+    beliefs: dict[str, HypothesisSet],
+    archetypes: dict[str, Any],
+    current: str | None = None,
+    min_observations: float = 1.0,
+) -> list[dict[str, Any]]:
+    """
+    Rank archetypes by how well they explain the answers so far.
+
+    Treats the evidence counts n_h (alpha minus the prior in effect) as draws
+    from each archetype's prior, and computes the posterior over archetypes
+    with a uniform archetype prior:
+
+        P(a | evidence) ∝ prod_dims prod_h p_a(h) ^ n_h
+
+    Only dimensions with at least min_observations of evidence count.
+
+    Args:
+        beliefs: Current belief state
+        archetypes: Archetype mapping from load_archetypes()["archetypes"]
+        current: Archetype currently applied (None = uniform prior)
+        min_observations: Minimum evidence for a dimension to be used
+
+    Returns:
+        Archetypes sorted by probability (desc), each with "archetype",
+        "probability" and "dimensions_used". Empty if no evidence yet.
+
+    Examples:
+        >>> archetypes = {
+        ...     "cli": {"priors": {"purpose": {"a": 0.8, "b": 0.2}}},
+        ...     "web": {"priors": {"purpose": {"a": 0.2, "b": 0.8}}},
+        ... }
+        >>> beliefs = {"purpose": HypothesisSet("purpose", ["a", "b"])}
+        >>> infer_archetype(beliefs, archetypes)
+        []
+        >>> beliefs["purpose"].update({"a": 0.9, "b": 0.1})
+        >>> beliefs["purpose"].update({"a": 0.9, "b": 0.1})
+        >>> ranking = infer_archetype(beliefs, archetypes)
+        >>> ranking[0]["archetype"]
+        'cli'
+        >>> ranking[0]["probability"] > 0.9
+        True
+        >>> ranking[0]["dimensions_used"]
+        ['purpose']
+    """
+    log_scores: dict[str, float] = {a: 0.0 for a in archetypes}
+    dimensions_used: list[str] = []
+
+    for dim_id, hs in beliefs.items():
+        base = archetype_alpha(archetypes, current, hs)
+        evidence = {h: max(0.0, hs.alpha[h] - base[h]) for h in hs.hypotheses}
+        if sum(evidence.values()) < min_observations:
+            continue
+        dimensions_used.append(dim_id)
+
+        for archetype in archetypes:
+            prior = archetype_alpha(archetypes, archetype, hs)
+            total = sum(prior.values())
+            for h, n in evidence.items():
+                p = max(prior[h] / total, HypothesisSet.EPSILON)
+                log_scores[archetype] += n * math.log(p)
+
+    if not dimensions_used:
+        return []
+
+    # Softmax over log scores (shifted by max for numerical stability)
+    max_score = max(log_scores.values())
+    weights = {a: math.exp(s - max_score) for a, s in log_scores.items()}
+    total_weight = sum(weights.values())
+
+    ranking = [
+        {
+            "archetype": a,
+            "probability": round(w / total_weight, 4),
+            "dimensions_used": dimensions_used,
+        }
+        for a, w in weights.items()
+    ]
+    ranking.sort(key=lambda r: r["probability"], reverse=True)
+    return ranking
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python archetype_priors.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
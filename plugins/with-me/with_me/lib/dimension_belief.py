@@ -620,6 +620,62 @@ def compute_jsd(p: dict[str, float], q: dict[str, float]) -> float:
     return max(0.0, jsd)  # Clamp floating-point negatives
 
 
+def diff_beliefs(
+    beliefs_a: dict[str, HypothesisSet], beliefs_b: dict[str, HypothesisSet]
+) -> list[dict[str, Any]]:
+    """
+    Compare two belief states dimension by dimension.
+
+    Used to measure how far two sessions (e.g. a session and its fork)
+    diverged. Only dimensions present in both states are compared.
+
+    Args:
+        beliefs_a: First belief state
+        beliefs_b: Second belief state
+
+    Returns:
+        Per-dimension diffs sorted by JSD (desc), each with "dimension",
+        "jsd", "entropy_a", "entropy_b", "most_likely_a", "most_likely_b"
+        and "most_likely_changed"
+
+    Examples:
+        >>> a = {"purpose": HypothesisSet("purpose", ["cli", "web"])}
+        >>> b = {"purpose": a["purpose"].copy()}
+        >>> a["purpose"].update({"cli": 0.9, "web": 0.1})
+        >>> b["purpose"].update({"cli": 0.1, "web": 0.9})
+        >>> [d] = diff_beliefs(a, b)
+        >>> d["most_likely_a"], d["most_likely_b"], d["most_likely_changed"]
+        ('cli', 'web', True)
+        >>> d["jsd"] > 0
+        True
+        >>> diff_beliefs(a, {"purpose": a["purpose"].copy()})[0]["jsd"]
+        0.0
+    """
+    diffs: list[dict[str, Any]] = []
+
+    for dim_id, hs_a in beliefs_a.items():
+        hs_b = beliefs_b.get(dim_id)
+        if hs_b is None:
+            continue
+
+        most_likely_a = hs_a.get_most_likely()
+        most_likely_b = hs_b.get_most_likely()
+        diffs.append(
+            {
+                "dimension": dim_id,
+                "jsd": round(compute_jsd(hs_a.posterior, hs_b.posterior), 4),
+                "entropy_a": round(hs_a.entropy(), 4),
+                "entropy_b": round(hs_b.entropy(), 4),
+                "most_likely_a": most_likely_a,
+                "most_likely_b": most_likely_b,
+                "most_likely_changed": most_likely_a != most_likely_b,
+            }
+        )
+
+    diffs.sort(key=lambda d: d["jsd"], reverse=True)
+    return diffs
+
+
 def create_default_dimension_beliefs() -> dict[str, HypothesisSet]:
     """
     Create default hypothesis sets for standard requirement dimensions.
@@ -0,0 +1,541 @@
+#!/usr/bin/env python3
+"""
+Pluggable likelihood models for belief updates.
+
+A likelihood model turns an answer into P(answer | hypothesis) for each
+hypothesis of a dimension. The update math (Dirichlet update, entropy, IG)
+stays the same; only the source of the likelihoods changes.
+
+Models:
+- explicit: Likelihoods estimated by Claude and passed on the command line
+- keyword_table: Keyword overlap between the answer and each hypothesis
+- logistic: Softmax over keyword overlap scores
+- sampling: Keyword table averaged over bootstrap resamples of the answer
+
+Keyword tables are built from each hypothesis' name, description, focus
+areas, and optional "keywords" list in dimensions.json.
+
+The model is chosen per dimension ("likelihood_model" in the dimension
+config) with session_config.likelihood_model as the default.
+
+Calibration:
+    Brier score and log loss of each update's normalized likelihoods against
+    the hypothesis the user confirmed for the dimension at completion. The
+    session's own posterior is not used as the outcome: it is computed from
+    the same likelihoods, so every model would look calibrated.
+
+Responsibilities:
+- LikelihoodModel interface and implementations
+- Model lookup from config
+- Calibration metrics per model
+"""
+
+import doctest
+import hashlib
+import math
+import random
+import re
+import sys
+from abc import ABC, abstractmethod
+from typing import Any
+
+from with_me.lib.dimension_belief import HypothesisSet
+
+# Minimum token length for keyword matching
+MIN_KEYWORD_LENGTH = 3
+# Common words that carry no signal about any hypothesis
+STOPWORDS = frozenset(
+    {
+        "and",
+        "are",
+        "for",
+        "from",
+        "into",
+        "its",
+        "not",
+        "that",
+        "the",
+        "this",
+        "with",
+        "such",
+    }
+)
+# Floor for probabilities in log loss
+LOG_LOSS_EPSILON = 1e-6
+
+
+def _tokens(text: str) -> list[str]:
+    """Lowercase word tokens without stopwords.
+
+    Examples:
+        >>> _tokens("The CLI runs in a terminal, with scripts")
+        ['cli', 'runs', 'terminal', 'scripts']
+    """
+    return [
+        w
+        for w in re.findall(r"\w+", text.lower())
+        if len(w) >= MIN_KEYWORD_LENGTH and w not in STOPWORDS
+    ]
+
+
+def build_keyword_table(dim_config: dict[str, Any]) -> dict[str, set[str]]:
+    """
+    Build hypothesis → keyword set from a dimension's config.
+
+    Args:
+        dim_config: Dimension entry from dimensions.json
+
+    Returns:
+        Keywords per hypothesis
+
+    Examples:
+        >>> config = {
+        ...     "hypotheses": {
+        ...         "cli_tool": {
+        ...             "name": "CLI Tool",
+        ...             "description": "Terminal utilities",
+        ...             "focus_areas": ["argument parsing"],
+        ...             "keywords": ["shell"],
+        ...         }
+        ...     }
+        ... }
+        >>> sorted(build_keyword_table(config)["cli_tool"])
+        ['argument', 'cli', 'parsing', 'shell', 'terminal', 'tool', 'utilities']
+    """
+    table: dict[str, set[str]] = {}
+    for h, hyp in dim_config.get("hypotheses", {}).items():
+        parts = [
+            hyp.get("name", ""),
+            hyp.get("description", ""),
+            *hyp.get("focus_areas", []),
+            *hyp.get("keywords", []),
+        ]
+        table[h] = set(_tokens(" ".join(parts)))
+    return table
+
+
+def _normalize(hs: HypothesisSet, values: dict[str, float]) -> dict[str, float]:
+    """Clamp negatives, fill missing with 0, normalize (uniform if all zero)."""
+    clamped = {h: max(0.0, float(values.get(h, 0.0))) for h in hs.hypotheses}
+    total = sum(clamped.values())
+    if total <= 0:
+        return {h: 1.0 / len(hs.hypotheses) for h in hs.hypotheses}
+    return {h: v / total for h, v in clamped.items()}
+
+
+class LikelihoodModel(ABC):
+    """Source of P(answer | hypothesis) for a dimension."""
+
+    name = ""
+
+    @abstractmethod
+    def likelihoods(
+        self,
+        hs: HypothesisSet,
+        answer: str,
+        provided: dict[str, float] | None = None,
+    ) -> dict[str, float]:
+        """
+        Compute normalized likelihoods for an answer.
+
+        Args:
+            hs: Hypothesis set of the dimension
+            answer: User's answer text
+            provided: Likelihoods estimated by Claude, if any
+
+        Returns:
+            Likelihood per hypothesis (sum = 1.0)
+        """
+
+
+class ExplicitModel(LikelihoodModel):
+    """
+    Likelihoods supplied by Claude (the original workflow).
+
+    Examples:
+        >>> hs = HypothesisSet("purpose", ["a", "b"])
+        >>> ExplicitModel().likelihoods(hs, "anything", {"a": 3.0, "b": 1.0})
+        {'a': 0.75, 'b': 0.25}
+        >>> ExplicitModel().likelihoods(hs, "anything")
+        Traceback (most recent call last):
+        ...
+        ValueError: explicit likelihood model requires provided likelihoods
+    """
+
+    name = "explicit"
+
+    def likelihoods(
+        self,
+        hs: HypothesisSet,
+        answer: str,
+        provided: dict[str, float] | None = None,
+    ) -> dict[str, float]:
+        """Normalize the provided likelihoods."""
+        if provided is None:
+            msg = "explicit likelihood model requires provided likelihoods"
+            raise ValueError(msg)
+        return _normalize(hs, provided)
+
+
+class KeywordTableModel(LikelihoodModel):
+    """
+    Likelihood proportional to smoothing + keyword matches.
+
+    Examples:
+        >>> hs = HypothesisSet("purpose", ["cli", "web"])
+        >>> table = {"cli": {"terminal", "shell"}, "web": {"browser"}}
+        >>> model = KeywordTableModel(table, smoothing=1.0)
+        >>> model.likelihoods(hs, "Run it from the terminal or shell")
+        {'cli': 0.75, 'web': 0.25}
+        >>> model.likelihoods(hs, "No idea")
+        {'cli': 0.5, 'web': 0.5}
+    """
+
+    name = "keyword_table"
+
+    def __init__(self, table: dict[str, set[str]], smoothing: float = 1.0):
+        self.table = table
+        self.smoothing = smoothing
+
+    def scores(self, hs: HypothesisSet, tokens: list[str]) -> dict[str, float]:
+        """Keyword match count per hypothesis."""
+        return {
+            h: float(sum(1 for t in tokens if t in self.table.get(h, set())))
+            for h in hs.hypotheses
+        }
+
+    def likelihoods(
+        self,
+        hs: HypothesisSet,
+        answer: str,
+        provided: dict[str, float] | None = None,
+    ) -> dict[str, float]:
+        """Likelihoods from keyword matches (provided is ignored)."""
+        scores = self.scores(hs, _tokens(answer))
+        return _normalize(hs, {h: self.smoothing + s for h, s in scores.items()})
+
+
+class LogisticModel(KeywordTableModel):
+    """
+    Softmax over keyword match scores: L(h) ∝ exp(score_h / temperature).
+
+    Examples:
+        >>> hs = HypothesisSet("purpose", ["cli", "web"])
+        >>> model = LogisticModel({"cli": {"terminal"}, "web": set()}, 1.0)
+        >>> round(model.likelihoods(hs, "terminal")["cli"], 4)
+        0.7311
+        >>> LogisticModel({}, temperature=0)
+        Traceback (most recent call last):
+        ...
+        ValueError: logistic temperature must be positive, got 0
+    """
+
+    name = "logistic"
+
+    def __init__(self, table: dict[str, set[str]], temperature: float = 1.0):
+        if temperature <= 0:
+            msg = f"logistic temperature must be positive, got {temperature}"
+            raise ValueError(msg)
+        super().__init__(table)
+        self.temperature = temperature
+
+    def likelihoods(
+        self,
+        hs: HypothesisSet,
+        answer: str,
+        provided: dict[str, float] | None = None,
+    ) -> dict[str, float]:
+        """Softmax likelihoods (provided is ignored)."""
+        scores = self.scores(hs, _tokens(answer))
+        max_score = max(scores.values()) if scores else 0.0
+        weights = {
+            h: math.exp((s - max_score) / self.temperature) for h, s in scores.items()
+        }
+        return _normalize(hs, weights)
+
+
+class SamplingModel(KeywordTableModel):
+    """
+    Keyword table averaged over bootstrap resamples of the answer tokens.
+
+    Resampling spreads probability when a single keyword drives the match,
+    so short answers produce softer updates. The generator is seeded from
+    the answer, making results reproducible.
+
+    Examples:
+        >>> hs = HypothesisSet("purpose", ["cli", "web"])
+        >>> model = SamplingModel({"cli": {"terminal"}, "web": set()}, samples=200)
+        >>> result = model.likelihoods(hs, "terminal app")
+        >>> 0.5 < result["cli"] < 0.67
+        True
+        >>> result == model.likelihoods(hs, "terminal app")
+        True
+        >>> SamplingModel({}, samples=0)
+        Traceback (most recent call last):
+        ...
+        ValueError: sampling samples must be positive, got 0
+        >>> SamplingModel({}, smoothing=-1.0)
+        Traceback (most recent call last):
+        ...
+        ValueError: sampling smoothing must be non-negative, got -1.0
+    """
+
+    name = "sampling"
+
+    def __init__(
+        self, table: dict[str, set[str]], samples: int = 50, smoothing: float = 1.0
+    ):
+        if samples <= 0:
+            msg = f"sampling samples must be positive, got {samples}"
+            raise ValueError(msg)
+        if smoothing < 0:
+            msg = f"sampling smoothing must be non-negative, got {smoothing}"
+            raise ValueError(msg)
+        super().__init__(table, smoothing)
+        self.samples = samples
+
+    def likelihoods(
+        self,
+        hs: HypothesisSet,
+        answer: str,
+        provided: dict[str, float] | None = None,
+    ) -> dict[str, float]:
+        """Bootstrap-averaged likelihoods (provided is ignored)."""
+        tokens = _tokens(answer)
+        if not tokens:
+            return _normalize(hs, {})
+
+        seed = int(hashlib.sha256(answer.encode("utf-8")).hexdigest()[:8], 16)
+        rng = random.Random(seed)
+        totals = dict.fromkeys(hs.hypotheses, 0.0)
+        for _ in range(self.samples):
+            resample = [rng.choice(tokens) for _ in tokens]
+            scores = self.scores(hs, resample)
+            sample = _normalize(hs, {h: self.smoothing + s for h, s in scores.items()})
+            for h, p in sample.items():
+                totals[h] += p
+        return _normalize(hs, totals)
+
+
+LIKELIHOOD_MODELS = ("explicit", "keyword_table", "logistic", "sampling")
+
+
+def create_likelihood_model(
+    name: str, dim_config: dict[str, Any], params: dict[str, Any] | None = None
+) -> LikelihoodModel:
+    """
+    Instantiate a likelihood model for a dimension.
+
+    Args:
+        name: Model name (see LIKELIHOOD_MODELS)
+        dim_config: Dimension entry from dimensions.json
+        params: Optional "smoothing", "temperature", "samples"
+
+    Returns:
+        Model instance
+
+    Raises:
+        ValueError: If name is unknown or a parameter is out of range
+
+    Examples:
+        >>> config = {"hypotheses": {"a": {"name": "Alpha"}}}
+        >>> create_likelihood_model("logistic", config).name
+        'logistic'
+        >>> create_likelihood_model("magic", config)
+        Traceback (most recent call last):
+        ...
+        ValueError: Unknown likelihood model: magic
+    """
+    params = params or {}
+    if name == "explicit":
+        return ExplicitModel()
+
+    table = build_keyword_table(dim_config)
+    if name == "keyword_table":
+        return KeywordTableModel(table, params.get("smoothing", 1.0))
+    if name == "logistic":
+        return LogisticModel(table, params.get("temperature", 1.0))
+    if name == "sampling":
+        return SamplingModel(
+            table, params.get("samples", 50), params.get("smoothing", 1.0)
+        )
+
+    msg = f"Unknown likelihood model: {name}"
+    raise ValueError(msg)
+
+
+def model_for_dimension(
+    config: dict[str, Any], dimension: str, override: str | None = None
+) -> LikelihoodModel:
+    """
+    Resolve the likelihood model configured for a dimension.
+
+    Precedence: override, dimension "likelihood_model",
+    session_config.likelihood_model, "explicit".
+
+    Examples:
+        >>> config = {
+        ...     "session_config": {"likelihood_model": "keyword_table"},
+        ...     "dimensions": {
+        ...         "purpose": {"hypotheses": {}, "likelihood_model": "logistic"},
+        ...         "data": {"hypotheses": {}},
+        ...     },
+        ... }
+        >>> model_for_dimension(config, "purpose").name
+        'logistic'
+        >>> model_for_dimension(config, "data").name
+        'keyword_table'
+        >>> model_for_dimension(config, "data", override="explicit").name
+        'explicit'
+    """
+    session_config = config.get("session_config", {})
+    dim_config = config["dimensions"][dimension]
+    name = (
+        override
+        or dim_config.get("likelihood_model")
+        or session_config.get("likelihood_model", "explicit")
+    )
+    return create_likelihood_model(
+        name, dim_config, session_config.get("likelihood_model_params")
+    )
+
+
+def brier_score(prediction: dict[str, float], outcome: str) -> float:
+    """
+    Multi-class Brier score: sum_h (p_h - [h == outcome])^2.
+
+    Examples:
+        >>> brier_score({"a": 1.0, "b": 0.0}, "a")
+        0.0
+        >>> brier_score({"a": 0.5, "b": 0.5}, "a")
+        0.5
+    """
+    return sum(
+        (p - (1.0 if h == outcome else 0.0)) ** 2 for h, p in prediction.items()
+    )
+
+
+def log_loss(prediction: dict[str, float], outcome: str) -> float:
+    """
+    Log loss in nats: -ln p(outcome), floored at LOG_LOSS_EPSILON.
+
+    Examples:
+        >>> round(log_loss({"a": 0.5, "b": 0.5}, "a"), 4)
+        0.6931
+        >>> round(log_loss({"a": 0.0, "b": 1.0}, "a"), 2)
+        13.82
+    """
+    return -math.log(max(prediction.get(outcome, 0.0), LOG_LOSS_EPSILON))
+
+
+def calibration_by_model(
+    question_history: list[dict[str, Any]],
+    confirmed: dict[str, str],
+) -> dict[str, dict[str, float]]:
+    """
+    Score each model's likelihoods against user-confirmed hypotheses.
+
+    Only updates on dimensions the user confirmed are scored; the others have
+    no outcome that is independent of the likelihoods themselves.
+
+    Args:
+        question_history: Session history (entries with "likelihoods" and
+                          "likelihood_model")
+        confirmed: {dimension: hypothesis} confirmed by the user
+
+    Returns:
+        {model: {"count", "brier", "log_loss"}} with mean scores
+
+    Examples:
+        >>> history = [
+        ...     {"dimension": "purpose", "likelihood_model": "explicit",
+        ...      "likelihoods": {"a": 0.9, "b": 0.1}},
+        ...     {"dimension": "purpose", "likelihood_model": "keyword_table",
+        ...      "likelihoods": {"a": 0.5, "b": 0.5}},
+        ...     {"dimension": "purpose", "answer": "no likelihoods recorded"},
+        ... ]
+        >>> result = calibration_by_model(history, {"purpose": "a"})
+        >>> result["explicit"]
+        {'count': 1, 'brier': 0.02, 'log_loss': 0.1054}
+        >>> result["keyword_table"]["brier"]
+        0.5
+        >>> # The user corrected the dimension: confident likelihoods score badly
+        >>> calibration_by_model(history, {"purpose": "b"})["explicit"]["brier"]
+        1.62
+        >>> calibration_by_model(history, {})
+        {}
+    """
+    sums: dict[str, dict[str, float]] = {}
+    for entry in question_history:
+        outcome = confirmed.get(entry.get("dimension", ""))
+        prediction = entry.get("likelihoods")
+        model = entry.get("likelihood_model")
+        if outcome is None or prediction is None or model is None:
+            continue
+        s = sums.setdefault(model, {"count": 0, "brier": 0.0, "log_loss": 0.0})
+        s["count"] += 1
+        s["brier"] += brier_score(prediction, outcome)
+        s["log_loss"] += log_loss(prediction, outcome)
+
+    return {
+        model: {
+            "count": int(s["count"]),
+            "brier": round(s["brier"] / s["count"], 4),
+            "log_loss": round(s["log_loss"] / s["count"], 4),
+        }
+        for model, s in sums.items()
+    }
+
+
+def validate_confirmed(confirmed: Any, dimensions_config: dict[str, Any]) -> list[str]:
+    """
+    Check user-confirmed hypotheses against the dimension config.
+
+    Examples:
+        >>> config = {"purpose": {"hypotheses": {"a": {}, "b": {}}}}
+        >>> validate_confirmed({"purpose": "a"}, config)
+        []
+        >>> validate_confirmed({"purpose": "c", "data": "x"}, config)
+        ["Unknown hypothesis for 'purpose': c", 'Unknown dimension: data']
+        >>> validate_confirmed(["a"], config)
+        ['Confirmed hypotheses must be an object']
+    """
+    if not isinstance(confirmed, dict):
+        return ["Confirmed hypotheses must be an object"]
+
+    errors: list[str] = []
+    for dim_id, hypothesis in confirmed.items():
+        if dim_id not in dimensions_config:
+            errors.append(f"Unknown dimension: {dim_id}")
+            continue
+        hypotheses = dimensions_config[dim_id].get("hypotheses", {})
+        if not isinstance(hypothesis, str) or hypothesis not in hypotheses:
+            errors.append(f"Unknown hypothesis for '{dim_id}': {hypothesis}")
+    return errors
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python likelihood_models.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,223 @@
+#!/usr/bin/env python3
+"""
+Append-only observation journal for belief updates.
+
+Every change to a session's beliefs is appended as one JSON line with its
+provenance, so the current beliefs can be audited: which question and answer
+moved which dimension, where the likelihoods came from, and what the
+posterior was before and after.
+
+Entry fields:
+- seq: Position in the journal (0-based)
+- timestamp: ISO timestamp (UTC)
+- kind: "answer", "secondary", "contradiction_resolution", or "archetype"
+- dimension: Dimension whose beliefs changed
+- likelihood_source: Likelihood model name, "presheaf:<edge>", or
+  "archetype:<id>"
+- prior / posterior: Posterior distribution before and after
+- optional provenance: question_id, question_index, question, answer,
+  likelihoods, weight
+
+question_id hashes the dimension and normalized question text the same way
+question bank IDs do, so journal entries can be matched to bank questions.
+
+Responsibilities:
+- Append entries (never rewrite existing lines)
+- Range reads by sequence number, optionally filtered by dimension
+- Restore a journal from an imported snapshot
+"""
+
+import doctest
+import hashlib
+import json
+import sys
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+
+def question_id(dimension: str, question: str) -> str:
+    """
+    Stable ID of a question from its dimension and normalized text.
+
+    Examples:
+        >>> question_id("stakeholders", "Who uses it?")
+        '5f8b33c2aa3c'
+        >>> question_id("stakeholders", "  who USES it? ")
+        '5f8b33c2aa3c'
+    """
+    key = f"{dimension}:{' '.join(question.lower().split())}"
+    return hashlib.sha256(key.encode("utf-8")).hexdigest()[:12]
+
+
+def count_entries(journal_file: Path) -> int:
+    """
+    Count journal entries.
+
+    Examples:
+        >>> count_entries(Path("/nonexistent/journal.jsonl"))
+        0
+    """
+    if not journal_file.exists():
+        return 0
+    with open(journal_file, encoding="utf-8") as f:
+        return sum(1 for line in f if line.strip())
+
+
+def append_entry(
+    journal_file: Path,
+    kind: str,
+    dimension: str,
+    likelihood_source: str,
+    prior: dict[str, float],
+    posterior: dict[str, float],
+    **provenance: Any,
+) -> int:
+    """
+    Append a belief update to the journal.
+
+    Args:
+        journal_file: JSONL journal (created if missing)
+        kind: Update kind
+        dimension: Dimension whose beliefs changed
+        likelihood_source: Where the likelihoods came from
+        prior: Posterior before the update
+        posterior: Posterior after the update
+        provenance: Extra fields (question_id, answer, likelihoods, ...)
+
+    Returns:
+        Sequence number of the new entry
+
+    Examples:
+        >>> import tempfile
+        >>> temp_dir = Path(tempfile.mkdtemp())
+        >>> journal = temp_dir / "journal.jsonl"
+        >>> append_entry(
+        ...     journal, "answer", "purpose", "explicit",
+        ...     {"a": 0.5, "b": 0.5}, {"a": 0.6, "b": 0.4}, answer="A",
+        ... )
+        0
+        >>> append_entry(
+        ...     journal, "secondary", "data", "explicit",
+        ...     {"x": 0.5, "y": 0.5}, {"x": 0.55, "y": 0.45},
+        ... )
+        1
+        >>> import shutil
+        >>> shutil.rmtree(temp_dir)
+    """
+    seq = count_entries(journal_file)
+    entry = {
+        "seq": seq,
+        "timestamp": datetime.now(tz=UTC).isoformat(),
+        "kind": kind,
+        "dimension": dimension,
+        "likelihood_source": likelihood_source,
+        "prior": {h: round(p, 4) for h, p in prior.items()},
+        "posterior": {h: round(p, 4) for h, p in posterior.items()},
+        **provenance,
+    }
+
+    journal_file.parent.mkdir(parents=True, exist_ok=True)
+    with open(journal_file, "a", encoding="utf-8") as f:
+        f.write(json.dumps(entry, ensure_ascii=False) + "\n")
+    return seq
+
+
+def read_entries(
+    journal_file: Path,
+    start: int = 0,
+    end: int | None = None,
+    dimension: str | None = None,
+) -> list[dict[str, Any]]:
+    """
+    Read journal entries with start <= seq < end.
+
+    Args:
+        journal_file: JSONL journal
+        start: First sequence number (inclusive)
+        end: Last sequence number (exclusive), None for all remaining
+        dimension: Only entries for this dimension (optional)
+
+    Returns:
+        Entries in journal order
+
+    Examples:
+        >>> import tempfile
+        >>> temp_dir = Path(tempfile.mkdtemp())
+        >>> journal = temp_dir / "journal.jsonl"
+        >>> for dim in ["purpose", "data", "purpose"]:
+        ...     _ = append_entry(journal, "answer", dim, "explicit", {}, {})
+        >>> [e["seq"] for e in read_entries(journal, start=1)]
+        [1, 2]
+        >>> [e["seq"] for e in read_entries(journal, end=2, dimension="purpose")]
+        [0]
+        >>> read_entries(temp_dir / "missing.jsonl")
+        []
+        >>> import shutil
+        >>> shutil.rmtree(temp_dir)
+    """
+    if not journal_file.exists():
+        return []
+
+    entries = []
+    with open(journal_file, encoding="utf-8") as f:
+        for line in f:
+            if not line.strip():
+                continue
+            entry = json.loads(line)
+            if entry["seq"] < start or (end is not None and entry["seq"] >= end):
+                continue
+            if dimension is not None and entry["dimension"] != dimension:
+                continue
+            entries.append(entry)
+    return entries
+
+
+def restore_journal(journal_file: Path, entries: list[dict[str, Any]]) -> None:
+    """
+    Write a journal from imported entries (replaces any existing file).
+
+    Only used when importing a session snapshot; normal updates must go
+    through append_entry().
+
+    Examples:
+        >>> import tempfile
+        >>> temp_dir = Path(tempfile.mkdtemp())
+        >>> journal = temp_dir / "journal.jsonl"
+        >>> restore_journal(journal, [{"seq": 0, "dimension": "purpose"}])
+        >>> append_entry(journal, "answer", "purpose", "explicit", {}, {})
+        1
+        >>> import shutil
+        >>> shutil.rmtree(temp_dir)
+    """
+    journal_file.parent.mkdir(parents=True, exist_ok=True)
+    with open(journal_file, "w", encoding="utf-8") as f:
+        for entry in entries:
+            f.write(json.dumps(entry, ensure_ascii=False) + "\n")
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python observation_journal.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -113,6 +113,64 @@ class RestrictionMap:
 
         return expected
 
+    def mutual_information(self, source_posterior: dict[str, float]) -> float:
+        """Compute mutual information I(S; T) implied by this edge.
+
+        Uses the joint p(s, t) = p(s) * P(t | s), where p(s) is the current
+        source posterior and the target marginal is expected_target():
+
+            I(S; T) = sum_s p(s) * sum_t P(t | s) * log2(P(t | s) / q(t))
+
+        Equivalently, the p(s)-weighted KL divergence of each conditional row
+        from the marginal. Measures how much knowing the source dimension
+        tells about the target dimension (coupling strength), in bits.
+
+        Args:
+            source_posterior: Source dimension's posterior {hyp: probability}
+
+        Returns:
+            Mutual information in bits (>= 0)
+
+        Examples:
+            >>> rm = RestrictionMap(
+            ...     "purpose",
+            ...     "data",
+            ...     {
+            ...         "web_app": {"structured": 1.0, "streaming": 0.0},
+            ...         "service": {"structured": 0.0, "streaming": 1.0},
+            ...     },
+            ... )
+            >>> # Deterministic map with uniform source → 1 bit
+            >>> round(rm.mutual_information({"web_app": 0.5, "service": 0.5}), 4)
+            1.0
+
+            >>> # Certain source → knowing it tells nothing new
+            >>> round(rm.mutual_information({"web_app": 1.0, "service": 0.0}), 4)
+            0.0
+
+            >>> # Identical rows → independent dimensions
+            >>> flat = RestrictionMap(
+            ...     "purpose",
+            ...     "data",
+            ...     {
+            ...         "web_app": {"structured": 0.5, "streaming": 0.5},
+            ...         "service": {"structured": 0.5, "streaming": 0.5},
+            ...     },
+            ... )
+            >>> round(flat.mutual_information({"web_app": 0.5, "service": 0.5}), 4)
+            0.0
+        """
+        marginal = self.expected_target(source_posterior)
+        mi = 0.0
+        for source_hyp, source_prob in source_posterior.items():
+            if source_prob <= 0 or source_hyp not in self.conditional:
+                continue
+            for target_hyp, cond_prob in self.conditional[source_hyp].items():
+                q = marginal.get(target_hyp, 0.0)
+                if cond_prob > 0 and q > 0:
+                    mi += source_prob * cond_prob * math.log2(cond_prob / q)
+        return max(0.0, mi)
+
 
 @dataclass
 class ConsistencyResult:
@@ -576,6 +634,392 @@ class PresheafChecker:
                 return result.jsd
         return 0.0
 
+    def coupling_matrix(
+        self, beliefs: dict[str, HypothesisSet]
+    ) -> list[dict[str, Any]]:
+        """Compute coupling metrics for every edge of the dimension DAG.
+
+        For each restriction map reports:
+        - mutual_information: I(S; T) in bits under current source beliefs
+        - normalized_mi: I(S; T) / min(H(S), H(T)), in [0, 1]
+        - jsd: consistency JSD between actual and expected target posteriors
+
+        Args:
+            beliefs: Current belief state
+
+        Returns:
+            One entry per edge, sorted by mutual information (desc)
+
+        Examples:
+            >>> maps = [
+            ...     RestrictionMap(
+            ...         "purpose",
+            ...         "data",
+            ...         {
+            ...             "web_app": {"structured": 0.9, "streaming": 0.1},
+            ...             "service": {"structured": 0.1, "streaming": 0.9},
+            ...         },
+            ...     ),
+            ...     RestrictionMap(
+            ...         "purpose",
+            ...         "behavior",
+            ...         {
+            ...             "web_app": {"sync": 0.6, "batch": 0.4},
+            ...             "service": {"sync": 0.4, "batch": 0.6},
+            ...         },
+            ...     ),
+            ... ]
+            >>> checker = PresheafChecker(maps)
+            >>> beliefs = {
+            ...     "purpose": HypothesisSet("purpose", ["web_app", "service"]),
+            ...     "data": HypothesisSet("data", ["structured", "streaming"]),
+            ...     "behavior": HypothesisSet("behavior", ["sync", "batch"]),
+            ... }
+            >>> matrix = checker.coupling_matrix(beliefs)
+            >>> [m["edge"] for m in matrix]
+            ['purpose->data', 'purpose->behavior']
+            >>> matrix[0]["mutual_information"] > matrix[1]["mutual_information"]
+            True
+            >>> 0.0 <= matrix[0]["normalized_mi"] <= 1.0
+            True
+        """
+        entries: list[dict[str, Any]] = []
+
+        for rm in self.restriction_maps:
+            if rm.source_dim not in beliefs or rm.target_dim not in beliefs:
+                continue
+
+            source_posterior = beliefs[rm.source_dim].posterior
+            expected = rm.expected_target(source_posterior)
+            mi = rm.mutual_information(source_posterior)
+
+            h_source = beliefs[rm.source_dim].entropy()
+            h_target = -sum(p * math.log2(p) for p in expected.values() if p > 0)
+            h_min = min(h_source, h_target)
+            normalized = mi / h_min if h_min > HypothesisSet.EPSILON else 0.0
+
+            jsd = compute_jsd(beliefs[rm.target_dim].posterior, expected)
+            entries.append(
+                {
+                    "edge": f"{rm.source_dim}->{rm.target_dim}",
+                    "source_dimension": rm.source_dim,
+                    "target_dimension": rm.target_dim,
+                    "mutual_information": round(mi, 4),
+                    "normalized_mi": round(min(1.0, normalized), 4),
+                    "jsd": round(jsd, 4),
+                }
+            )
+
+        entries.sort(key=lambda e: e["mutual_information"], reverse=True)
+        return entries
+
+    def coupled_dimensions(
+        self,
+        dimension: str,
+        beliefs: dict[str, HypothesisSet],
+        threshold: float = 0.1,
+    ) -> list[dict[str, Any]]:
+        """Get dimensions strongly coupled to a dimension (either edge direction).
+
+        Strongly coupled dimensions are good candidates for batching: a single
+        answer tends to inform both, so their questions can be asked together.
+
+        Args:
+            dimension: Dimension ID
+            beliefs: Current belief state
+            threshold: Minimum mutual information (bits)
+
+        Returns:
+            Neighbor dimensions with "dimension" and "mutual_information",
+            sorted by mutual information (desc)
+
+        Examples:
+            >>> maps = [
+            ...     RestrictionMap(
+            ...         "purpose",
+            ...         "data",
+            ...         {
+            ...             "web_app": {"structured": 0.9, "streaming": 0.1},
+            ...             "service": {"structured": 0.1, "streaming": 0.9},
+            ...         },
+            ...     ),
+            ... ]
+            >>> checker = PresheafChecker(maps)
+            >>> beliefs = {
+            ...     "purpose": HypothesisSet("purpose", ["web_app", "service"]),
+            ...     "data": HypothesisSet("data", ["structured", "streaming"]),
+            ... }
+            >>> [c["dimension"] for c in checker.coupled_dimensions("data", beliefs)]
+            ['purpose']
+            >>> checker.coupled_dimensions("data", beliefs, threshold=0.9)
+            []
+        """
+        coupled: list[dict[str, Any]] = []
+
+        for entry in self.coupling_matrix(beliefs):
+            if entry["mutual_information"] < threshold:
+                continue
+            if entry["source_dimension"] == dimension:
+                neighbor = entry["target_dimension"]
+            elif entry["target_dimension"] == dimension:
+                neighbor = entry["source_dimension"]
+            else:
+                continue
+            coupled.append(
+                {
+                    "dimension": neighbor,
+                    "mutual_information": entry["mutual_information"],
+                }
+            )
+
+        return coupled
+
+    def find_map(self, source: str, target: str) -> RestrictionMap | None:
+        """Find the restriction map for a DAG edge.
+
+        Args:
+            source: Source dimension ID
+            target: Target dimension ID
+
+        Returns:
+            RestrictionMap for the edge, or None if the edge is not defined
+
+        Examples:
+            >>> rm = RestrictionMap("purpose", "data", {"web_app": {"structured": 1.0}})
+            >>> checker = PresheafChecker([rm])
+            >>> checker.find_map("purpose", "data") is rm
+            True
+            >>> checker.find_map("data", "purpose") is None
+            True
+        """
+        for rm in self.restriction_maps:
+            if rm.source_dim == source and rm.target_dim == target:
+                return rm
+        return None
+
+    def describe_contradictions(
+        self, beliefs: dict[str, HypothesisSet]
+    ) -> list[dict[str, Any]]:
+        """Describe inconsistent edges so the user can pick which belief to keep.
+
+        For each inconsistent edge, reports the most likely hypothesis on both
+        sides together with the target hypothesis the restriction map predicts
+        from the source beliefs. These are the two competing claims presented
+        to the user during contradiction resolution.
+
+        Args:
+            beliefs: Current belief state
+
+        Returns:
+            List of contradiction descriptions, highest JSD first
+
+        Examples:
+            >>> rm = RestrictionMap(
+            ...     "purpose",
+            ...     "data",
+            ...     {
+            ...         "web_app": {"structured": 0.8, "streaming": 0.2},
+            ...         "service": {"structured": 0.2, "streaming": 0.8},
+            ...     },
+            ... )
+            >>> checker = PresheafChecker([rm], consistency_threshold=0.1)
+            >>> beliefs = {
+            ...     "purpose": HypothesisSet(
+            ...         "purpose",
+            ...         ["web_app", "service"],
+            ...         alpha={"web_app": 9.0, "service": 1.0},
+            ...     ),
+            ...     "data": HypothesisSet(
+            ...         "data",
+            ...         ["structured", "streaming"],
+            ...         alpha={"structured": 1.0, "streaming": 9.0},
+            ...     ),
+            ... }
+            >>> [c] = checker.describe_contradictions(beliefs)
+            >>> c["edge"]
+            'purpose->data'
+            >>> c["source_most_likely"], c["target_most_likely"]
+            ('web_app', 'streaming')
+            >>> c["expected_target_most_likely"]
+            'structured'
+
+            >>> # Consistent beliefs → nothing to resolve
+            >>> beliefs["data"] = HypothesisSet(
+            ...     "data",
+            ...     ["structured", "streaming"],
+            ...     alpha={"structured": 7.0, "streaming": 3.0},
+            ... )
+            >>> checker.describe_contradictions(beliefs)
+            []
+        """
+        contradictions: list[dict[str, Any]] = []
+
+        for result in self.get_inconsistencies(beliefs):
+            rm = self.find_map(result.source_dim, result.target_dim)
+            if rm is None:
+                continue
+            expected = rm.expected_target(beliefs[result.source_dim].posterior)
+            contradictions.append(
+                {
+                    "edge": f"{result.source_dim}->{result.target_dim}",
+                    "source_dimension": result.source_dim,
+                    "target_dimension": result.target_dim,
+                    "jsd": round(result.jsd, 4),
+                    "source_most_likely": beliefs[result.source_dim].get_most_likely(),
+                    "target_most_likely": beliefs[result.target_dim].get_most_likely(),
+                    "expected_target_most_likely": max(
+                        expected.items(), key=lambda x: x[1]
+                    )[0],
+                }
+            )
+
+        contradictions.sort(key=lambda c: c["jsd"], reverse=True)
+        return contradictions
+
+    def resolve_contradiction(
+        self,
+        beliefs: dict[str, HypothesisSet],
+        source: str,
+        target: str,
+        endorsed: str,
+        dampening: float = 0.5,
+    ) -> dict[str, Any]:
+        """Apply a dampened corrective update after the user resolves a conflict.
+
+        The endorsed side is kept as-is and the other side is nudged towards it:
+        - endorsed="source": the target is updated with the restriction map's
+          expected target distribution as likelihood.
+        - endorsed="target": the source is updated with
+          L(s) = sum_t p_target(t) * P(t | s), i.e. how well each source
+          hypothesis explains the current target beliefs.
+
+        The update is additive (Dirichlet) with weight=dampening, so a single
+        resolution cannot overwrite beliefs accumulated from direct answers.
+
+        Args:
+            beliefs: Current belief state (modified in place)
+            source: Source dimension ID of the inconsistent edge
+            target: Target dimension ID of the inconsistent edge
+            endorsed: Which side the user endorses ("source" or "target")
+            dampening: Weight of the corrective update
+
+        Returns:
+            Resolution record with edge JSD and corrected dimension entropy
+            before and after the update
+
+        Raises:
+            ValueError: If the edge is unknown or endorsed is invalid
+
+        Examples:
+            >>> rm = RestrictionMap(
+            ...     "purpose",
+            ...     "data",
+            ...     {
+            ...         "web_app": {"structured": 0.8, "streaming": 0.2},
+            ...         "service": {"structured": 0.2, "streaming": 0.8},
+            ...     },
+            ... )
+            >>> checker = PresheafChecker([rm], consistency_threshold=0.1)
+            >>> def conflicting():
+            ...     return {
+            ...         "purpose": HypothesisSet(
+            ...             "purpose",
+            ...             ["web_app", "service"],
+            ...             alpha={"web_app": 9.0, "service": 1.0},
+            ...         ),
+            ...         "data": HypothesisSet(
+            ...             "data",
+            ...             ["structured", "streaming"],
+            ...             alpha={"structured": 1.0, "streaming": 9.0},
+            ...         ),
+            ...     }
+
+            >>> # User endorses purpose → data is pulled towards "structured"
+            >>> beliefs = conflicting()
+            >>> record = checker.resolve_contradiction(
+            ...     beliefs, "purpose", "data", "source", dampening=5.0
+            ... )
+            >>> record["corrected_dimension"]
+            'data'
+            >>> record["jsd_after"] < record["jsd_before"]
+            True
+            >>> beliefs["purpose"].alpha["web_app"]  # Endorsed side untouched
+            9.0
+
+            >>> # User endorses data → purpose is pulled towards "service"
+            >>> beliefs = conflicting()
+            >>> record = checker.resolve_contradiction(
+            ...     beliefs, "purpose", "data", "target", dampening=5.0
+            ... )
+            >>> record["corrected_dimension"]
+            'purpose'
+            >>> beliefs["purpose"].alpha["service"] > 1.0
+            True
+
+            >>> checker.resolve_contradiction(beliefs, "data", "purpose", "source")
+            Traceback (most recent call last):
+            ...
+            ValueError: No restriction map for edge: data->purpose
+            >>> checker.resolve_contradiction(beliefs, "purpose", "data", "both")
+            Traceback (most recent call last):
+            ...
+            ValueError: endorsed must be 'source' or 'target', got: both
+        """
+        rm = self.find_map(source, target)
+        if rm is None or source not in beliefs or target not in beliefs:
+            msg = f"No restriction map for edge: {source}->{target}"
+            raise ValueError(msg)
+        if endorsed not in ("source", "target"):
+            msg = f"endorsed must be 'source' or 'target', got: {endorsed}"
+            raise ValueError(msg)
+
+        source_hs = beliefs[source]
+        target_hs = beliefs[target]
+        jsd_before = compute_jsd(
+            target_hs.posterior, rm.expected_target(source_hs.posterior)
+        )
+
+        if endorsed == "source":
+            corrected = target_hs
+            raw = rm.expected_target(source_hs.posterior)
+        else:
+            corrected = source_hs
+            target_posterior = target_hs.posterior
+            raw = {
+                s: sum(
+                    target_posterior.get(t, 0.0) * p
+                    for t, p in rm.conditional.get(s, {}).items()
+                )
+                for s in source_hs.hypotheses
+            }
+
+        total = sum(raw.get(h, 0.0) for h in corrected.hypotheses)
+        if total > 0:
+            likelihoods = {h: raw.get(h, 0.0) / total for h in corrected.hypotheses}
+        else:
+            likelihoods = {
+                h: 1.0 / len(corrected.hypotheses) for h in corrected.hypotheses
+            }
+
+        entropy_before = corrected.entropy()
+        corrected.update(likelihoods, weight=dampening)
+        entropy_after = corrected.entropy()
+
+        jsd_after = compute_jsd(
+            target_hs.posterior, rm.expected_target(source_hs.posterior)
+        )
+
+        return {
+            "edge": f"{source}->{target}",
+            "endorsed": endorsed,
+            "corrected_dimension": corrected.dimension,
+            "weight": dampening,
+            "jsd_before": round(jsd_before, 4),
+            "jsd_after": round(jsd_after, 4),
+            "entropy_before": round(entropy_before, 4),
+            "entropy_after": round(entropy_after, 4),
+        }
+
 
 def load_restriction_maps(config: dict[str, Any]) -> list[RestrictionMap]:
     """Parse restriction maps from dimensions.json config.
@@ -0,0 +1,511 @@
+#!/usr/bin/env python3
+"""
+Question bank for requirement elicitation.
+
+Stores reusable candidate questions tagged with the dimension they target
+and likelihood templates (P(answer | hypothesis) per answer option), so good
+questions can be retrieved instead of regenerated every session.
+
+Sources:
+- Markdown files (one "## question" section per question)
+- JSON files ({"questions": [...]}), also used for questions added at runtime
+
+Responsibilities:
+- Parse and load questions from markdown/JSON
+- Search questions by keyword, dimension, tags, and DAG frontier
+- Append new questions to a JSON bank file
+"""
+
+import doctest
+import json
+import re
+import sys
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any
+
+from with_me.lib.observation_journal import question_id
+
+# Minimum token length for keyword matching (skips "a", "is", ...)
+MIN_KEYWORD_LENGTH = 3
+
+
+@dataclass
+class BankQuestion:
+    """A candidate question with its dimension and likelihood templates.
+
+    Args:
+        text: Question text
+        dimension: Dimension ID the question targets
+        tags: Search tags
+        likelihood_templates: {answer: {hypothesis: P(answer | hypothesis)}}
+        source: Where the question was loaded from
+
+    Examples:
+        >>> q = BankQuestion("Who uses it?", "stakeholders", ["users"])
+        >>> q.id == BankQuestion("who uses it? ", "stakeholders").id
+        True
+        >>> q.id  # Same as observation_journal.question_id()
+        '5f8b33c2aa3c'
+    """
+
+    text: str
+    dimension: str
+    tags: list[str] = field(default_factory=list)
+    likelihood_templates: dict[str, dict[str, float]] = field(default_factory=dict)
+    source: str = ""
+
+    @property
+    def id(self) -> str:
+        """Stable ID derived from dimension and normalized question text."""
+        return question_id(self.dimension, self.text)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Serialize for JSON output and storage.
+
+        Examples:
+            >>> q = BankQuestion("Who uses it?", "stakeholders")
+            >>> sorted(q.to_dict())
+            ['dimension', 'id', 'likelihood_templates', 'source', 'tags', 'text']
+        """
+        return {"id": self.id, **asdict(self)}
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any], source: str = "") -> "BankQuestion":
+        """Deserialize from a JSON bank entry.
+
+        Examples:
+            >>> q = BankQuestion.from_dict({"text": "Why?", "dimension": "purpose"})
+            >>> q.tags, q.likelihood_templates
+            ([], {})
+        """
+        return cls(
+            text=data["text"],
+            dimension=data["dimension"],
+            tags=list(data.get("tags", [])),
+            likelihood_templates=dict(data.get("likelihood_templates", {})),
+            source=data.get("source") or source,
+        )
+
+
+def parse_markdown_questions(text: str, source: str = "") -> list[BankQuestion]:
+    """
+    Parse questions from markdown.
+
+    Format (per question):
+        ## <question text>
+        dimension: <dimension_id>
+        tags: <tag>, <tag>
+        - <answer>: {"<hypothesis>": <probability>, ...}
+
+    Sections without a dimension line are skipped. Answer lines whose
+    likelihoods are not valid JSON objects are ignored.
+
+    Args:
+        text: Markdown content
+        source: Source label stored on each question
+
+    Returns:
+        Parsed questions in document order
+
+    Examples:
+        >>> md = '''# Bank
+        ... ## Who uses it?
+        ... dimension: stakeholders
+        ... tags: users, audience
+        ... - Just me: {"individual_user": 0.9, "team": 0.1}
+        ... - Broken: {not json}
+        ...
+        ... ## No dimension here
+        ... - A: {"x": 1.0}
+        ... '''
+        >>> [q] = parse_markdown_questions(md)
+        >>> q.text, q.dimension, q.tags
+        ('Who uses it?', 'stakeholders', ['users', 'audience'])
+        >>> q.likelihood_templates
+        {'Just me': {'individual_user': 0.9, 'team': 0.1}}
+    """
+    questions: list[BankQuestion] = []
+    current: BankQuestion | None = None
+    answer_pattern = re.compile(r"^-\s+(.+?):\s*(\{.*\})\s*$")
+
+    def flush() -> None:
+        if current is not None and current.dimension:
+            questions.append(current)
+
+    for raw_line in text.splitlines():
+        line = raw_line.strip()
+        if line.startswith("## "):
+            flush()
+            current = BankQuestion(text=line[3:].strip(), dimension="", source=source)
+            continue
+        if current is None:
+            continue
+
+        if line.startswith("dimension:"):
+            current.dimension = line.split(":", 1)[1].strip()
+        elif line.startswith("tags:"):
+            tags = line.split(":", 1)[1].split(",")
+            current.tags = [t.strip() for t in tags if t.strip()]
+        else:
+            match = answer_pattern.match(line)
+            if match is None:
+                continue
+            try:
+                likelihoods = json.loads(match.group(2))
+            except json.JSONDecodeError:
+                continue
+            if isinstance(likelihoods, dict):
+                current.likelihood_templates[match.group(1).strip()] = likelihoods
+
+    flush()
+    return questions
+
+
+def _entry_error(entry: Any) -> str | None:
+    """
+    Describe why a JSON bank entry is invalid.
+
+    Examples:
+        >>> _entry_error({"text": "Why?", "dimension": "purpose", "tags": ["users"]})
+        >>> _entry_error({"text": "Why?"})
+        'needs string "text" and "dimension" fields'
+        >>> _entry_error({"text": "Why?", "dimension": "purpose", "tags": "users"})
+        '"tags" must be a list of strings'
+    """
+    if (
+        not isinstance(entry, dict)
+        or not isinstance(entry.get("text"), str)
+        or not isinstance(entry.get("dimension"), str)
+    ):
+        return 'needs string "text" and "dimension" fields'
+
+    templates = entry.get("likelihood_templates", {})
+    if not isinstance(templates, dict) or not all(
+        isinstance(likelihoods, dict) for likelihoods in templates.values()
+    ):
+        return '"likelihood_templates" must map answers to objects'
+
+    tags = entry.get("tags", [])
+    if not isinstance(tags, list) or not all(isinstance(tag, str) for tag in tags):
+        return '"tags" must be a list of strings'
+
+    return None
+
+
+def _bank_entries(data: Any) -> list[dict[str, Any]]:
+    """
+    Extract question entries from parsed JSON bank content.
+
+    Invalid entries (see _entry_error) are skipped with a warning.
+
+    Raises:
+        ValueError: If the content is not {"questions": [...]}
+
+    Examples:
+        >>> import contextlib, io
+        >>> why = {"text": "Why?", "dimension": "purpose"}
+        >>> with contextlib.redirect_stderr(io.StringIO()) as err:
+        ...     _bank_entries({"questions": [why, 1]})
+        [{'text': 'Why?', 'dimension': 'purpose'}]
+        >>> "Skipping question bank entry 1" in err.getvalue()
+        True
+        >>> with contextlib.redirect_stderr(io.StringIO()) as err:
+        ...     _bank_entries({"questions": [{**why, "likelihood_templates": [1]}]})
+        []
+        >>> '"likelihood_templates" must map answers to objects' in err.getvalue()
+        True
+        >>> with contextlib.redirect_stderr(io.StringIO()) as err:
+        ...     _bank_entries({"questions": [{**why, "tags": "users"}]})
+        []
+        >>> print(err.getvalue().strip())
+        Warning: Skipping question bank entry 0: "tags" must be a list of strings
+        >>> _bank_entries([1])
+        Traceback (most recent call last):
+        ...
+        ValueError: Question bank must be an object with a "questions" list
+    """
+    if not isinstance(data, dict) or not isinstance(data.get("questions", []), list):
+        msg = 'Question bank must be an object with a "questions" list'
+        raise ValueError(msg)
+
+    entries = []
+    for index, entry in enumerate(data.get("questions", [])):
+        error = _entry_error(entry)
+        if error is None:
+            entries.append(entry)
+        else:
+            print(
+                f"Warning: Skipping question bank entry {index}: {error}",
+                file=sys.stderr,
+            )
+    return entries
+
+
+def load_bank(paths: list[Path]) -> list[BankQuestion]:
+    """
+    Load questions from markdown and JSON files, skipping duplicates.
+
+    Missing files are ignored, and malformed JSON banks are skipped with a
+    warning. Later files cannot override earlier questions with the same ID
+    (first occurrence wins).
+
+    Args:
+        paths: Bank files (.md or .json)
+
+    Returns:
+        Deduplicated questions
+
+    Examples:
+        >>> import tempfile
+        >>> temp_dir = Path(tempfile.mkdtemp())
+        >>> md_file = temp_dir / "bank.md"
+        >>> _ = md_file.write_text("## Why?\\ndimension: purpose\\n")
+        >>> json_file = temp_dir / "bank.json"
+        >>> _ = json_file.write_text(
+        ...     json.dumps(
+        ...         {
+        ...             "questions": [
+        ...                 {"text": "Why?", "dimension": "purpose"},
+        ...                 {"text": "How fast?", "dimension": "constraints"},
+        ...             ]
+        ...         }
+        ...     )
+        ... )
+        >>> bank = load_bank([md_file, json_file, temp_dir / "missing.json"])
+        >>> [(q.text, q.source) for q in bank]
+        [('Why?', 'bank.md'), ('How fast?', 'bank.json')]
+        >>> list_file = temp_dir / "list.json"
+        >>> _ = list_file.write_text("[1]")
+        >>> import contextlib, io
+        >>> with contextlib.redirect_stderr(io.StringIO()):
+        ...     load_bank([list_file])
+        []
+        >>> import shutil
+        >>> shutil.rmtree(temp_dir)
+    """
+    questions: list[BankQuestion] = []
+    seen: set[str] = set()
+
+    for path in paths:
+        if not path.exists():
+            continue
+
+        content = path.read_text(encoding="utf-8")
+        if path.suffix == ".md":
+            loaded = parse_markdown_questions(content, source=path.name)
+        else:
+            try:
+                entries = _bank_entries(json.loads(content))
+            except (json.JSONDecodeError, ValueError) as e:
+                print(
+                    f"Warning: Could not load question bank {path}: {e}",
+                    file=sys.stderr,
+                )
+                continue
+            loaded = [BankQuestion.from_dict(q, source=path.name) for q in entries]
+
+        for q in loaded:
+            if q.id not in seen:
+                seen.add(q.id)
+                questions.append(q)
+
+    return questions
+
+
+def _keywords(text: str) -> set[str]:
+    """Lowercase word tokens used for keyword matching.
+
+    Examples:
+        >>> sorted(_keywords("Who will USE it, day-to-day?"))
+        ['day', 'use', 'who', 'will']
+    """
+    return {
+        w for w in re.findall(r"\w+", text.lower()) if len(w) >= MIN_KEYWORD_LENGTH
+    }
+
+
+def search_questions(
+    questions: list[BankQuestion],
+    query: str | None = None,
+    dimension: str | None = None,
+    tags: list[str] | None = None,
+    frontier: list[str] | None = None,
+    limit: int = 5,
+) -> list[BankQuestion]:
+    """
+    Search the bank.
+
+    Filters by dimension, tags (all must match), and DAG frontier (accessible
+    dimensions), then ranks by keyword overlap between query and question
+    text/tags. Without a query, bank order is kept.
+
+    Args:
+        questions: Loaded bank
+        query: Free-text query (optional)
+        dimension: Only questions for this dimension (optional)
+        tags: Only questions carrying all these tags (optional)
+        frontier: Only questions for these dimensions (optional)
+        limit: Maximum number of results
+
+    Returns:
+        Matching questions, best first
+
+    Examples:
+        >>> bank = [
+        ...     BankQuestion("Who will use it?", "stakeholders", ["users"]),
+        ...     BankQuestion("How fast must it respond?", "constraints", ["latency"]),
+        ...     BankQuestion("What data comes in?", "data", ["input"]),
+        ... ]
+        >>> [q.dimension for q in search_questions(bank, query="respond fast")]
+        ['constraints']
+        >>> [q.dimension for q in search_questions(bank, tags=["users"])]
+        ['stakeholders']
+        >>> found = search_questions(bank, frontier=["data", "stakeholders"])
+        >>> [q.dimension for q in found]
+        ['stakeholders', 'data']
+        >>> search_questions(bank, dimension="quality")
+        []
+    """
+    candidates = [
+        q
+        for q in questions
+        if (dimension is None or q.dimension == dimension)
+        and (frontier is None or q.dimension in frontier)
+        and (not tags or all(t in q.tags for t in tags))
+    ]
+
+    if query:
+        query_words = _keywords(query)
+        scored = []
+        for q in candidates:
+            overlap = len(query_words & (_keywords(q.text) | set(q.tags)))
+            if overlap > 0:
+                scored.append((overlap, q))
+        scored.sort(key=lambda x: x[0], reverse=True)
+        candidates = [q for _, q in scored]
+
+    return candidates[:limit]
+
+
+def add_question(bank_file: Path, question: BankQuestion) -> bool:
+    """
+    Append a question to a JSON bank file.
+
+    Args:
+        bank_file: JSON bank file (created if missing)
+        question: Question to add
+
+    Returns:
+        True if added, False if a question with the same ID already exists
+
+    Raises:
+        ValueError: If the existing bank file is malformed
+
+    Examples:
+        >>> import tempfile
+        >>> temp_dir = Path(tempfile.mkdtemp())
+        >>> bank_file = temp_dir / "question_bank.json"
+        >>> q = BankQuestion(
+        ...     "Who will use it?",
+        ...     "stakeholders",
+        ...     likelihood_templates={"Me": {"individual_user": 0.9}},
+        ... )
+        >>> add_question(bank_file, q)
+        True
+        >>> add_question(bank_file, q)
+        False
+        >>> [b.text for b in load_bank([bank_file])]
+        ['Who will use it?']
+        >>> import shutil
+        >>> shutil.rmtree(temp_dir)
+    """
+    entries: list[dict[str, Any]] = []
+    if bank_file.exists():
+        with open(bank_file, encoding="utf-8") as f:
+            entries = _bank_entries(json.load(f))
+
+    if any(BankQuestion.from_dict(q).id == question.id for q in entries):
+        return False
+
+    entry = asdict(question)
+    entry["source"] = bank_file.name
+    data = {"questions": [*entries, entry]}
+
+    bank_file.parent.mkdir(parents=True, exist_ok=True)
+    with open(bank_file, "w", encoding="utf-8") as f:
+        json.dump(data, f, indent=2, ensure_ascii=False)
+    return True
+
+
+def validate_templates(
+    question: BankQuestion, dimension_config: dict[str, Any]
+) -> list[str]:
+    """
+    Check likelihood templates against a dimension's hypotheses.
+
+    Args:
+        question: Question to validate
+        dimension_config: Dimension entry from dimensions.json
+
+    Returns:
+        Error messages (empty if valid)
+
+    Examples:
+        >>> config = {"hypotheses": {"a": {}, "b": {}}}
+        >>> q = BankQuestion("Q?", "dim", likelihood_templates={"X": {"a": 0.9}})
+        >>> validate_templates(q, config)
+        []
+        >>> q.likelihood_templates["Y"] = {"c": 0.5, "a": -1}
+        >>> validate_templates(q, config)
+        ["Answer 'Y': unknown hypothesis 'c'", "Answer 'Y': invalid likelihood for 'a'"]
+        >>> validate_templates(BankQuestion("Q?", "dim", [], {"X": 0.5}), config)
+        ["Answer 'X': likelihoods must be an object"]
+        >>> validate_templates(BankQuestion("Q?", "dim", [], [1]), config)
+        ['Likelihood templates must be an object']
+    """
+    templates: Any = question.likelihood_templates
+    if not isinstance(templates, dict):
+        return ["Likelihood templates must be an object"]
+
+    errors: list[str] = []
+    hypotheses = set(dimension_config.get("hypotheses", {}))
+
+    for answer, likelihoods in templates.items():
+        if not isinstance(likelihoods, dict):
+            errors.append(f"Answer '{answer}': likelihoods must be an object")
+            continue
+        for h, v in likelihoods.items():
+            if h not in hypotheses:
+                errors.append(f"Answer '{answer}': unknown hypothesis '{h}'")
+            elif isinstance(v, bool) or not isinstance(v, int | float) or v < 0:
+                errors.append(f"Answer '{answer}': invalid likelihood for '{h}'")
+
+    return errors
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python question_bank.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -13,6 +13,8 @@ from datetime import datetime
 from pathlib import Path
 from typing import Any, NotRequired, TypedDict
 
+from with_me.lib.strategy_bandit import StrategyBandit
+
 # Thresholds
 UNCERTAINTY_RESOLVED_THRESHOLD = 0.3  # Uncertainty threshold for dimension resolution
 
@@ -106,6 +108,8 @@ class SessionData(TypedDict):
     Extensions:
     - initial_dimension_beliefs: Starting posterior distributions
     - final_dimension_beliefs: Ending posterior distributions
+    - with_me_session_id: with-me session the record belongs to, when it was
+      started separately from the session (``feedback start <SESSION_ID>``)
     """
 
     session_id: str
@@ -117,6 +121,7 @@ class SessionData(TypedDict):
     # Bayesian belief tracking
     initial_dimension_beliefs: NotRequired[dict[str, dict] | None]
     final_dimension_beliefs: NotRequired[dict[str, dict] | None]
+    with_me_session_id: NotRequired[str]
 
 
 class FeedbackData(TypedDict):
@@ -124,6 +129,19 @@ class FeedbackData(TypedDict):
 
     sessions: list[SessionData]
     statistics: dict[str, Any]
+    strategy_stats: NotRequired[dict[str, dict[str, Any]]]
+    likelihood_calibration: NotRequired[dict[str, dict[str, float]]]
+
+
+def empty_statistics() -> dict[str, Any]:
+    """Global statistics for a feedback file without completed sessions."""
+    return {
+        "total_sessions": 0,
+        "total_questions": 0,
+        "avg_questions_per_session": 0.0,
+        "best_questions": [],
+        "dimension_stats": {},
+    }
 
 
 def load_feedback(feedback_file: Path) -> FeedbackData:
@@ -153,13 +171,7 @@ def load_feedback(feedback_file: Path) -> FeedbackData:
     """
     default_data: FeedbackData = {
         "sessions": [],
-        "statistics": {
-            "total_sessions": 0,
-            "total_questions": 0,
-            "avg_questions_per_session": 0.0,
-            "best_questions": [],
-            "dimension_stats": {},
-        },
+        "statistics": empty_statistics(),
     }
 
     if not feedback_file.exists():
@@ -238,13 +250,16 @@ class QuestionFeedbackManager:
         self.data = load_feedback(feedback_file)
 
     def start_session(
-        self, initial_dimension_beliefs: dict[str, dict] | None = None
+        self,
+        initial_dimension_beliefs: dict[str, dict] | None = None,
+        with_me_session_id: str | None = None,
     ) -> str:
         """
         Start a new session
 
         Args:
             initial_dimension_beliefs: Optional initial Bayesian beliefs
+            with_me_session_id: Optional with-me session to link the record to
 
         Returns:
             Session ID (ISO timestamp)
@@ -266,6 +281,8 @@ class QuestionFeedbackManager:
             "initial_dimension_beliefs": initial_dimension_beliefs,
             "final_dimension_beliefs": None,
         }
+        if with_me_session_id is not None:
+            session["with_me_session_id"] = with_me_session_id
 
         self.data["sessions"].append(session)
         save_feedback(self.feedback_file, self.data)
@@ -415,6 +432,94 @@ class QuestionFeedbackManager:
         """
         return self.data.get("statistics", {})
 
+    def get_strategy_stats(self) -> dict[str, dict[str, Any]]:
+        """
+        Get per-strategy bandit statistics accumulated across sessions
+
+        Returns:
+            Statistics dict, shared with self.data (mutations are persisted
+            on the next save)
+        """
+        return self.data.setdefault("strategy_stats", {})
+
+    def record_strategy_outcome(
+        self, strategy: str, information_gain: float, tokens: int
+    ) -> float:
+        """
+        Credit a question-selection strategy with a realized outcome
+
+        Args:
+            strategy: Strategy that selected the question
+            information_gain: Realized information gain in bits
+            tokens: Estimated tokens spent on question and answer
+
+        Returns:
+            Reward credited to the strategy
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> manager = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> manager.record_strategy_outcome("breadth_first", 0.5, 50)
+            1.0
+            >>> reloaded = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> reloaded.get_strategy_stats()["breadth_first"]["pulls"]
+            1
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        bandit = StrategyBandit(self.get_strategy_stats())
+        reward = bandit.record(strategy, information_gain, tokens)
+        save_feedback(self.feedback_file, self.data)
+        return reward
+
+    def record_calibration(self, calibration: dict[str, dict[str, float]]) -> None:
+        """
+        Accumulate one session's likelihood model calibration
+
+        Args:
+            calibration: {model: {"count", "brier", "log_loss"}} with
+                         per-session mean scores
+        """
+        totals = self.data.setdefault("likelihood_calibration", {})
+        for model, scores in calibration.items():
+            t = totals.setdefault(
+                model, {"count": 0, "brier_sum": 0.0, "log_loss_sum": 0.0}
+            )
+            t["count"] += scores["count"]
+            t["brier_sum"] += scores["brier"] * scores["count"]
+            t["log_loss_sum"] += scores["log_loss"] * scores["count"]
+        save_feedback(self.feedback_file, self.data)
+
+    def get_calibration_summary(self) -> dict[str, dict[str, float]]:
+        """
+        Get mean Brier score and log loss per likelihood model across sessions
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> manager = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> manager.record_calibration(
+            ...     {"explicit": {"count": 2, "brier": 0.2, "log_loss": 0.4}}
+            ... )
+            >>> manager.record_calibration(
+            ...     {"explicit": {"count": 2, "brier": 0.4, "log_loss": 0.6}}
+            ... )
+            >>> manager.get_calibration_summary()
+            {'explicit': {'count': 4, 'brier': 0.3, 'log_loss': 0.5}}
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        return {
+            model: {
+                "count": t["count"],
+                "brier": round(t["brier_sum"] / t["count"], 4),
+                "log_loss": round(t["log_loss_sum"] / t["count"], 4),
+            }
+            for model, t in self.data.get("likelihood_calibration", {}).items()
+            if t["count"] > 0
+        }
+
     def get_recent_sessions(self, limit: int = 5) -> list[SessionData]:
         """
         Get most recent sessions
@@ -428,6 +533,125 @@ class QuestionFeedbackManager:
         sessions = self.data.get("sessions", [])
         return sessions[-limit:]
 
+    def find_sessions(
+        self, session_ids: set[str], started_before: datetime | None = None
+    ) -> list[str]:
+        """
+        Find session records belonging to with-me sessions or started before a time
+
+        A record matches when its own ID or its linked with-me session ID is in
+        session_ids, or when it was started before started_before.
+
+        Args:
+            session_ids: with-me session identifiers
+            started_before: Optional naive local cutoff for started_at
+
+        Returns:
+            Matching record IDs
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> manager = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> linked = manager.start_session(with_me_session_id="abc")
+            >>> manager.find_sessions({"abc"}) == [linked]
+            True
+            >>> manager.find_sessions({"xyz"})
+            []
+            >>> manager.find_sessions(set(), datetime.now()) == [linked]
+            True
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        return [
+            s["session_id"]
+            for s in self.data["sessions"]
+            if s["session_id"] in session_ids
+            or s.get("with_me_session_id") in session_ids
+            or (
+                started_before is not None
+                and datetime.fromisoformat(s["started_at"]) < started_before
+            )
+        ]
+
+    def find_unlinked_sessions(self, known_session_ids: set[str]) -> list[str]:
+        """
+        Find session records not linked to any known with-me session
+
+        Records started with a bare ``feedback start`` carry no with-me session
+        ID, so a purge by session ID cannot tell whether they belong to it.
+
+        Args:
+            known_session_ids: with-me session identifiers that still exist
+
+        Returns:
+            Record IDs without a link whose own ID is not a known session
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> manager = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> bare = manager.start_session()
+            >>> _ = manager.start_session(with_me_session_id="abc")
+            >>> manager.find_unlinked_sessions(set()) == [bare]
+            True
+            >>> manager.find_unlinked_sessions({bare})
+            []
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        return [
+            s["session_id"]
+            for s in self.data["sessions"]
+            if "with_me_session_id" not in s
+            and s["session_id"] not in known_session_ids
+        ]
+
+    def remove_sessions(
+        self, session_ids: set[str], started_before: datetime | None = None
+    ) -> list[str]:
+        """
+        Remove matching session records and recompute global statistics
+
+        Records are matched as in find_sessions. Strategy and calibration
+        statistics are aggregates without session references, so they are kept.
+
+        Args:
+            session_ids: with-me session identifiers
+            started_before: Optional naive local cutoff for started_at
+
+        Returns:
+            Removed record IDs
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> manager = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> session_id = manager.start_session()
+            >>> manager.complete_session(session_id, {})["total_questions"]
+            0
+            >>> linked = manager.start_session(with_me_session_id=session_id)
+            >>> manager.remove_sessions({session_id}) == [session_id, linked]
+            True
+            >>> manager.remove_sessions({session_id})
+            []
+            >>> reloaded = QuestionFeedbackManager(temp_dir / "feedback.json")
+            >>> reloaded.data["sessions"], reloaded.get_statistics()["total_sessions"]
+            ([], 0)
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        matched = set(self.find_sessions(session_ids, started_before))
+        if not matched:
+            return []
+
+        sessions = self.data["sessions"]
+        self.data["sessions"] = [s for s in sessions if s["session_id"] not in matched]
+        self.data["statistics"] = empty_statistics()
+        self._update_statistics()
+        save_feedback(self.feedback_file, self.data)
+        return [s["session_id"] for s in sessions if s["session_id"] in matched]
+
     def _find_session(self, session_id: str) -> SessionData | None:
         """Find session by ID"""
         for session in self.data["sessions"]:
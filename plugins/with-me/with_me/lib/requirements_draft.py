@@ -0,0 +1,294 @@
+#!/usr/bin/env python3
+"""
+Render a session's beliefs for humans and for prompts.
+
+The draft is a markdown preview of what the session has established so far.
+It is regenerated after every belief update, so it can be watched while the
+interview runs. The understanding block is a compact, token-budgeted summary
+for injection into a system prompt.
+
+Sections:
+- Goals: resolved purpose, stakeholder, and quality dimensions
+- Constraints: resolved context, data, behavior, and constraint dimensions
+- Open Questions: dimensions whose entropy is above the open threshold
+- Consistency Warnings: DAG edges flagged by the presheaf checker
+
+Responsibilities:
+- Classify dimensions as resolved or open
+- Render markdown with supporting answers from the question history
+- Render the understanding block within a token budget
+"""
+
+import doctest
+import sys
+from typing import Any
+
+from with_me.lib.session_orchestrator import (
+    CONFIDENCE_THRESHOLD_FOR_DISPLAY,
+    SessionOrchestrator,
+)
+from with_me.lib.strategy_bandit import estimate_tokens
+
+# Dimension grouping for resolved sections
+DRAFT_SECTIONS = {
+    "Goals": ["purpose", "stakeholders", "quality"],
+    "Constraints": ["context", "data", "behavior", "constraints"],
+}
+
+# Leading hypotheses listed for each open dimension
+OPEN_CANDIDATES = 2
+
+
+def _supporting_answers(
+    question_history: list[dict[str, Any]], dimension: str
+) -> list[str]:
+    """Answers given to questions that targeted a dimension, oldest first."""
+    return [
+        entry["answer"]
+        for entry in question_history
+        if entry.get("dimension") == dimension and entry.get("answer")
+    ]
+
+
+def render_draft(orch: SessionOrchestrator, open_threshold: float) -> str:
+    """
+    Render the current belief state as a markdown requirements draft.
+
+    A dimension is open while its entropy (bits) is above open_threshold;
+    otherwise it is resolved and listed under its section with the most
+    likely hypothesis.
+
+    Args:
+        orch: Session with loaded beliefs and question history
+        open_threshold: Entropy (bits) above which a dimension is open
+
+    Returns:
+        Markdown document
+
+    Examples:
+        >>> from pathlib import Path
+        >>> orch = SessionOrchestrator(
+        ...     feedback_file_path=Path("/tmp/test_feedback.json")
+        ... )
+        >>> _ = orch.initialize_session()
+        >>> hs = orch.beliefs["purpose"]
+        >>> hs.alpha = {h: 30.0 if h == "cli_tool" else 1.0 for h in hs.hypotheses}
+        >>> hs._cached_entropy = hs.entropy()
+        >>> hs._cached_confidence = 1.0 - hs._cached_entropy / 2.0
+        >>> orch.question_history = [
+        ...     {"dimension": "purpose", "answer": "A terminal tool for logs"}
+        ... ]
+        >>> draft = render_draft(orch, open_threshold=1.0)
+        >>> "- **Purpose**: CLI Tool" in draft
+        True
+        >>> "> A terminal tool for logs" in draft
+        True
+        >>> "### Data" in draft.split("## Open Questions")[1]
+        True
+    """
+    state = orch.get_current_state()
+    dims_config = orch.config["dimensions"]
+    open_dims = [
+        dim_id
+        for dim_id, dim_state in state["dimensions"].items()
+        if dim_state["entropy"] > open_threshold
+    ]
+
+    lines = [
+        "# Requirements Draft",
+        "",
+        f"Session: {state['session_id']} | Questions asked: "
+        f"{state['question_count']}",
+        "",
+    ]
+
+    for section, dim_ids in DRAFT_SECTIONS.items():
+        lines.extend([f"## {section}", ""])
+        resolved = [d for d in dim_ids if d in orch.beliefs and d not in open_dims]
+        if not resolved:
+            lines.extend(["_Nothing resolved yet._", ""])
+            continue
+
+        for dim_id in resolved:
+            hs = orch.beliefs[dim_id]
+            dim_config = dims_config[dim_id]
+            top = hs.get_most_likely()
+            hyp_config = dim_config["hypotheses"][top]
+            confidence = state["dimensions"][dim_id]["confidence"]
+            lines.append(
+                f"- **{dim_config['name']}**: {hyp_config['name']} "
+                f"(confidence {confidence:.2f})"
+            )
+            lines.append(f"  {hyp_config['description']}")
+            for answer in _supporting_answers(orch.question_history, dim_id):
+                lines.append(f"  > {answer}")
+        lines.append("")
+
+    lines.extend(["## Open Questions", ""])
+    if not open_dims:
+        lines.extend(["_None._", ""])
+    for dim_id in open_dims:
+        hs = orch.beliefs[dim_id]
+        dim_config = dims_config[dim_id]
+        dim_state = state["dimensions"][dim_id]
+        ranked = sorted(hs.posterior.items(), key=lambda x: x[1], reverse=True)
+        candidates = ", ".join(
+            f"{dim_config['hypotheses'][h]['name']} ({p:.2f})"
+            for h, p in ranked[:OPEN_CANDIDATES]
+        )
+        lines.extend(
+            [
+                f"### {dim_config['name']}",
+                "",
+                f"- Entropy: {dim_state['entropy']:.2f} bits",
+                f"- Leading candidates: {candidates}",
+            ]
+        )
+        if dim_state["blocked_by"]:
+            lines.append(f"- Blocked by: {', '.join(dim_state['blocked_by'])}")
+        focus = dim_config.get("focus_areas", [])
+        if focus:
+            lines.append(f"- To clarify: {', '.join(focus)}")
+        lines.append("")
+
+    warnings = [c for c in state["consistency"] if not c["is_consistent"]]
+    if warnings:
+        lines.extend(["## Consistency Warnings", ""])
+        lines.extend(f"- {c['edge']} (JSD {c['jsd']:.2f})" for c in warnings)
+        lines.append("")
+
+    return "\n".join(lines)
+
+
+def render_understanding(
+    orch: SessionOrchestrator, max_tokens: int
+) -> dict[str, Any]:
+    """
+    Render a compact "current understanding" block for prompt injection.
+
+    One line per dimension with the top hypothesis, its ID (usable with
+    complete --confirmed) and confidence, most confident first. Near-uniform
+    dimensions still show their top hypothesis, marked as low confidence.
+    Dimensions below target_confidence are flagged as unresolved. If the
+    block exceeds max_tokens, the least confident lines are dropped and
+    replaced with a count of omitted dimensions. The budget covers the
+    whole block: if even the count and tags do not fit, they are dropped
+    too and the block is empty.
+
+    Args:
+        orch: Session with loaded beliefs
+        max_tokens: Token budget for the whole block
+
+    Returns:
+        Dict with "block" (text), "tokens" (estimated), "truncated" (bool)
+        and "omitted_dimensions" (dimension IDs left out)
+
+    Examples:
+        >>> from pathlib import Path
+        >>> orch = SessionOrchestrator(
+        ...     feedback_file_path=Path("/tmp/test_feedback.json")
+        ... )
+        >>> _ = orch.initialize_session()
+        >>> hs = orch.beliefs["purpose"]
+        >>> hs.alpha = {h: 100.0 if h == "cli_tool" else 1.0 for h in hs.hypotheses}
+        >>> hs._cached_confidence = 1.0 - hs.entropy() / 2.0
+        >>> result = render_understanding(orch, max_tokens=500)
+        >>> print(result["block"].splitlines()[1])
+        - Purpose: CLI Tool [cli_tool] (0.88)
+        >>> print(result["block"].splitlines()[-2])
+        - Quality: Functional Quality [functional] (0.00, low confidence) [unresolved]
+        >>> result["truncated"]
+        False
+
+        >>> small = render_understanding(orch, max_tokens=40)
+        >>> small["truncated"], small["tokens"] <= 40
+        (True, True)
+        >>> "purpose" in small["omitted_dimensions"]
+        False
+
+        >>> tiny = render_understanding(orch, max_tokens=3)
+        >>> tiny["block"], tiny["tokens"], len(tiny["omitted_dimensions"])
+        ('', 0, 7)
+        >>> tags_only = render_understanding(orch, max_tokens=12)
+        >>> tags_only["tokens"] <= 12, "omitted" in tags_only["block"]
+        (True, False)
+    """
+    target_confidence = orch.config["session_config"].get("target_confidence", 0.85)
+    dims_config = orch.config["dimensions"]
+
+    rows = []
+    for dim_id, hs in orch.beliefs.items():
+        confidence = (
+            hs._cached_confidence if hs._cached_confidence is not None else 0.0
+        )
+        dim_config = dims_config[dim_id]
+        top = hs.get_most_likely()
+        top_name = dim_config["hypotheses"][top]["name"]
+        # Near-uniform beliefs: the top hypothesis is barely ahead of the rest
+        low = (
+            "" if confidence > CONFIDENCE_THRESHOLD_FOR_DISPLAY else ", low confidence"
+        )
+        flag = "" if confidence >= target_confidence else " [unresolved]"
+        line = (
+            f"- {dim_config['name']}: {top_name} [{top}] ({confidence:.2f}{low}){flag}"
+        )
+        rows.append((confidence, dim_id, line))
+    rows.sort(key=lambda r: r[0], reverse=True)
+
+    header = "<current-understanding>"
+    footer = "</current-understanding>"
+    kept = [line for _, _, line in rows]
+    omitted: list[str] = []
+    show_count = True
+
+    def build() -> str:
+        body = list(kept)
+        if omitted and show_count:
+            body.append(f"- ({len(omitted)} more dimensions omitted)")
+        return "\n".join([header, *body, footer])
+
+    block = build()
+    while kept and estimate_tokens(block) > max_tokens:
+        kept.pop()
+        omitted.insert(0, rows[len(kept)][1])
+        block = build()
+    if estimate_tokens(block) > max_tokens:
+        # Even the omitted-dimension count does not fit
+        show_count = False
+        block = build()
+    if estimate_tokens(block) > max_tokens:
+        block = ""
+
+    return {
+        "block": block,
+        "tokens": estimate_tokens(block) if block else 0,
+        "truncated": bool(omitted),
+        "omitted_dimensions": omitted,
+    }
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python requirements_draft.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -27,6 +27,7 @@ from with_me.lib.dimension_belief import (
 )
 from with_me.lib.presheaf import PresheafChecker, load_restriction_maps
 from with_me.lib.question_feedback_manager import QuestionFeedbackManager
+from with_me.lib.strategy_bandit import STRATEGIES, StrategyBandit
 
 # Thresholds
 CONFIDENCE_THRESHOLD_FOR_DISPLAY = (
@@ -101,6 +102,10 @@ class SessionOrchestrator:
         self.question_count = 0
         self.recent_information_gains: list[float] = []
         self.thompson_states: dict[str, dict[str, float]] = {}
+        self.contradiction_resolutions: list[dict[str, Any]] = []
+        self.archetype: str | None = None
+        self.pending_strategy: str | None = None
+        self.forked_from: dict[str, Any] | None = None
 
     def initialize_session(self) -> str:
         """
@@ -135,6 +140,10 @@ class SessionOrchestrator:
         self.question_history = []
         self.question_count = 0
         self.recent_information_gains = []
+        self.contradiction_resolutions = []
+        self.archetype = None
+        self.pending_strategy = None
+        self.forked_from = None
 
         # Initialize Thompson Sampling states per dimension
         self.thompson_states = {}
@@ -150,6 +159,45 @@ class SessionOrchestrator:
 
         return self.session_id
 
+    def fork_session(self) -> str:
+        """
+        Branch the current session into a new one for what-if exploration.
+
+        The fork keeps beliefs, question history and sampling state, gets a
+        new session ID (and feedback session), and records its origin in
+        forked_from. The caller persists it under the new ID.
+
+        Returns:
+            Session ID of the fork
+
+        Examples:
+            >>> from pathlib import Path
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=Path("/tmp/test_feedback.json")
+            ... )
+            >>> original_id = orch.initialize_session()
+            >>> orch.question_history = [{"dimension": "purpose"}]
+            >>> fork_id = orch.fork_session()
+            >>> fork_id != original_id and orch.session_id == fork_id
+            True
+            >>> orch.forked_from == {
+            ...     "session_id": original_id,
+            ...     "question_index": 1,
+            ... }
+            True
+        """
+        source_id = self.session_id
+        self.session_id = self.manager.start_session(
+            initial_dimension_beliefs={k: v.to_dict() for k, v in self.beliefs.items()}
+        )
+        self.forked_from = {
+            "session_id": source_id,
+            "question_index": len(self.question_history),
+        }
+        # A pending strategy belongs to a question asked in the parent
+        self.pending_strategy = None
+        return self.session_id
+
     def record_information_gain(self, ig: float) -> None:
         """
         Record information gain from a question for diminishing returns tracking.
@@ -313,6 +361,24 @@ class SessionOrchestrator:
 
         return accessible
 
+    def get_frontier(self) -> list[str]:
+        """
+        Get the DAG frontier: dimensions whose prerequisites are satisfied.
+
+        Returns:
+            Accessible dimension IDs
+
+        Examples:
+            >>> from pathlib import Path
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=Path("/tmp/test_feedback.json")
+            ... )
+            >>> _ = orch.initialize_session()
+            >>> sorted(orch.get_frontier())
+            ['context', 'purpose']
+        """
+        return [d[0] for d in self._get_accessible_dimensions()]
+
     def select_next_dimension(self, deterministic: bool = True) -> str | None:
         """
         Select next dimension to query.
@@ -375,10 +441,7 @@ class SessionOrchestrator:
             return None
 
         if deterministic:
-            # Greedy: sort by epistemic score (desc), then importance (desc)
-            # epistemic_score = epistemic_entropy / H_max (4th element)
-            accessible.sort(key=lambda x: (x[3], x[2]), reverse=True)
-            return accessible[0][0]
+            return self._greedy_dimension(accessible)
 
         # Thompson Sampling: sample Beta(alpha, beta) per accessible dimension
         best_dim = None
@@ -393,6 +456,189 @@ class SessionOrchestrator:
 
         return best_dim
 
+    @staticmethod
+    def _greedy_dimension(accessible: list[tuple[str, float, float, float]]) -> str:
+        """Highest epistemic score (4th element), ties broken by importance."""
+        return max(accessible, key=lambda x: (x[3], x[2]))[0]
+
+    def _paced_out_dimension(self) -> str | None:
+        """
+        Dimension asked max_consecutive_same_dimension times in a row, if any.
+
+        Examples:
+            >>> from pathlib import Path
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=Path("/tmp/test_feedback.json")
+            ... )
+            >>> orch.question_history = [{"dimension": "purpose"}] * 3
+            >>> orch._paced_out_dimension()
+            'purpose'
+            >>> orch.question_history[-1] = {"dimension": "context"}
+            >>> orch._paced_out_dimension() is None
+            True
+        """
+        limit = self.config["session_config"].get("max_consecutive_same_dimension", 3)
+        recent = [entry["dimension"] for entry in self.question_history[-limit:]]
+        if len(recent) == limit and len(set(recent)) == 1:
+            return recent[0]
+        return None
+
+    def select_strategy(self, rng: random.Random | None = None) -> str:
+        """
+        Select a question-selection strategy with the cross-session bandit.
+
+        Uses strategy_algorithm, strategy_epsilon and strategy_ucb_exploration
+        from session_config, and statistics stored in the feedback file.
+
+        Args:
+            rng: Random source (for reproducibility)
+
+        Returns:
+            Strategy ID
+
+        Examples:
+            >>> import tempfile
+            >>> temp_dir = Path(tempfile.mkdtemp())
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=temp_dir / "feedback.json"
+            ... )
+            >>> _ = orch.initialize_session()
+            >>> orch.select_strategy()  # UCB tries untried strategies first
+            'max_eig'
+            >>> import shutil
+            >>> shutil.rmtree(temp_dir)
+        """
+        session_config = self.config.get("session_config", {})
+        bandit = StrategyBandit(
+            self.manager.get_strategy_stats(),
+            algorithm=session_config.get("strategy_algorithm", "ucb"),
+            epsilon=session_config.get("strategy_epsilon", 0.1),
+            exploration=session_config.get("strategy_ucb_exploration", 1.0),
+        )
+        return bandit.select(rng)
+
+    def select_dimension_by_strategy(self, strategy: str) -> str | None:
+        """
+        Select next dimension following a question-selection strategy.
+
+        - max_eig: greedy epistemic score (as select_next_dimension())
+        - breadth_first: accessible dimension asked least often so far,
+          ties broken by epistemic score
+        - user_comfort: previous dimension if still accessible and below
+          target_confidence, otherwise max_eig
+
+        All strategies follow the pacing rule: after
+        max_consecutive_same_dimension questions in a row on one dimension,
+        another accessible dimension is chosen if there is one.
+
+        Args:
+            strategy: Strategy ID
+
+        Returns:
+            Dimension ID, or None if no accessible dimensions
+
+        Raises:
+            ValueError: If strategy is unknown
+
+        Examples:
+            >>> from pathlib import Path
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=Path("/tmp/test_feedback.json")
+            ... )
+            >>> _ = orch.initialize_session()
+            >>> orch.beliefs["purpose"]._cached_entropy = 1.0
+            >>> orch.beliefs["context"]._cached_entropy = 1.0
+            >>> orch.question_history = [{"dimension": "purpose"}]
+            >>> orch.select_dimension_by_strategy("user_comfort")
+            'purpose'
+            >>> orch.select_dimension_by_strategy("breadth_first") != "purpose"
+            True
+
+            >>> # Pacing: three purpose questions in a row force a switch
+            >>> orch.question_history = [{"dimension": "purpose"}] * 3
+            >>> orch.select_dimension_by_strategy("user_comfort") != "purpose"
+            True
+            >>> orch.select_dimension_by_strategy("random")
+            Traceback (most recent call last):
+            ...
+            ValueError: Unknown strategy: random
+        """
+        if strategy not in STRATEGIES:
+            msg = f"Unknown strategy: {strategy}"
+            raise ValueError(msg)
+
+        accessible = self._get_accessible_dimensions()
+        if not accessible:
+            return None
+
+        # Pacing rule, unless the paced-out dimension is the only one open
+        paced_out = self._paced_out_dimension()
+        others = [d for d in accessible if d[0] != paced_out]
+        if others:
+            accessible = others
+
+        if strategy == "breadth_first":
+            asked: dict[str, int] = {}
+            for entry in self.question_history:
+                asked[entry["dimension"]] = asked.get(entry["dimension"], 0) + 1
+            accessible.sort(key=lambda x: (-asked.get(x[0], 0), x[3]), reverse=True)
+            return accessible[0][0]
+
+        if strategy == "user_comfort":
+            # Open = confidence (1 - normalized entropy) below target
+            target_confidence = self.config["session_config"].get(
+                "target_confidence", 0.85
+            )
+            if self.question_history:
+                previous = self.question_history[-1]["dimension"]
+                for dim_id, norm_entropy, _importance, _epi in accessible:
+                    if dim_id == previous and 1.0 - norm_entropy < target_confidence:
+                        return dim_id
+
+        # max_eig, and user_comfort when the previous dimension is closed
+        return self._greedy_dimension(accessible)
+
+    def get_batch_candidates(self, dimension: str) -> list[dict[str, Any]]:
+        """
+        Get accessible dimensions coupled strongly enough to batch with a question.
+
+        Uses mutual information along DAG edges (presheaf restriction maps).
+        Dimensions above coupling_batch_threshold that are not blocked by
+        prerequisites can be asked about in the same question round.
+
+        Args:
+            dimension: Dimension selected for the next question
+
+        Returns:
+            Coupled accessible dimensions with mutual information (bits)
+
+        Examples:
+            >>> from pathlib import Path
+            >>> orch = SessionOrchestrator(
+            ...     feedback_file_path=Path("/tmp/test_feedback.json")
+            ... )
+            >>> _ = orch.initialize_session()
+            >>> # Nothing besides purpose/context is accessible at start
+            >>> orch.get_batch_candidates("purpose")
+            []
+
+            >>> # Once purpose is clear enough, coupled dimensions open up
+            >>> orch.beliefs["purpose"]._cached_entropy = 1.0
+            >>> candidates = orch.get_batch_candidates("purpose")
+            >>> "behavior" in [c["dimension"] for c in candidates]
+            True
+        """
+        threshold = self.config["session_config"].get("coupling_batch_threshold", 0.1)
+        accessible = {d[0] for d in self._get_accessible_dimensions()}
+
+        return [
+            c
+            for c in self.presheaf_checker.coupled_dimensions(
+                dimension, self.beliefs, threshold
+            )
+            if c["dimension"] in accessible and c["dimension"] != dimension
+        ]
+
     def update_thompson_state(self, dimension: str, information_gain: float) -> None:
         """
         Update Thompson Sampling state based on information gain.
@@ -469,12 +715,17 @@ class SessionOrchestrator:
     #
     # Reward function: r(Q) = EIG(Q) + 0.1 * clarity(Q) + 0.05 * importance(Q)
 
-    def select_next_question(self) -> tuple[str, str] | tuple[None, None]:
+    def select_next_question(
+        self, strategy: str = "max_eig"
+    ) -> tuple[str, str] | tuple[None, None]:
         """
         Select next question to ask user.
 
         Combines dimension selection with question generation.
 
+        Args:
+            strategy: Question-selection strategy (see select_dimension_by_strategy)
+
         Returns:
             Tuple of (dimension, question) or (None, None) if converged
 
@@ -491,7 +742,7 @@ class SessionOrchestrator:
             True
         """
         # Select dimension
-        dimension = self.select_next_dimension()
+        dimension = self.select_dimension_by_strategy(strategy)
         if dimension is None:
             return None, None
 
@@ -0,0 +1,284 @@
+#!/usr/bin/env python3
+"""
+Versioned session file schema with migration, export, and import.
+
+Session files written before versioning have no "schema_version" and are
+treated as version 1. Loading always migrates to SCHEMA_VERSION, so older
+sessions keep working after new state is added.
+
+Versions:
+- 1: session_id, beliefs, question_history, question_count
+     (recent_information_gains, thompson_states optional)
+- 2: adds schema_version and guarantees recent_information_gains,
+     thompson_states, contradiction_resolutions, archetype, pending_strategy,
+     forked_from
+
+Snapshots bundle a session file with its observation journal so in-flight
+sessions can move between workspaces without data loss.
+
+Responsibilities:
+- Migrate session state to the current schema
+- Validate required fields and session IDs
+- Build and unpack export snapshots
+"""
+
+import copy
+import doctest
+import re
+import sys
+from datetime import UTC, datetime
+from typing import Any
+
+SCHEMA_VERSION = 2
+SNAPSHOT_FORMAT = "with-me-session"
+
+REQUIRED_FIELDS = ("session_id", "beliefs", "question_history", "question_count")
+
+# Session IDs become file names, so they must not contain path separators or
+# start with a dot (native IDs are ISO timestamps like 2025-01-01T12:00:00.0)
+SESSION_ID_PATTERN = re.compile(r"[A-Za-z0-9][A-Za-z0-9_.:-]*")
+
+# Defaults for fields introduced in version 2
+V2_DEFAULTS: dict[str, Any] = {
+    "recent_information_gains": [],
+    "thompson_states": {},
+    "contradiction_resolutions": [],
+    "archetype": None,
+    "pending_strategy": None,
+    "forked_from": None,
+}
+
+
+def _migrate_v1_to_v2(state: dict[str, Any]) -> None:
+    """Fill fields introduced in version 2 (in place)."""
+    for key, default in V2_DEFAULTS.items():
+        state.setdefault(key, copy.deepcopy(default))
+
+
+# Migration from version N to N + 1, keyed by N
+MIGRATIONS = {1: _migrate_v1_to_v2}
+
+
+def migrate_state(state: dict[str, Any]) -> dict[str, Any]:
+    """
+    Migrate session state to SCHEMA_VERSION.
+
+    Args:
+        state: Session file contents (not modified)
+
+    Returns:
+        Migrated copy
+
+    Raises:
+        ValueError: If the state was written by a newer schema
+
+    Examples:
+        >>> legacy = {"session_id": "s", "beliefs": {}, "question_history": [],
+        ...           "question_count": 0}
+        >>> migrated = migrate_state(legacy)
+        >>> migrated["schema_version"], migrated["archetype"]
+        (2, None)
+        >>> "schema_version" in legacy
+        False
+        >>> migrate_state({**legacy, "schema_version": 99})
+        Traceback (most recent call last):
+        ...
+        ValueError: Unsupported session schema version: 99 (max 2)
+    """
+    migrated = copy.deepcopy(state)
+    version = migrated.get("schema_version", 1)
+
+    if version > SCHEMA_VERSION:
+        msg = f"Unsupported session schema version: {version} (max {SCHEMA_VERSION})"
+        raise ValueError(msg)
+
+    while version < SCHEMA_VERSION:
+        MIGRATIONS[version](migrated)
+        version += 1
+
+    migrated["schema_version"] = version
+    return migrated
+
+
+def is_valid_session_id(session_id: Any) -> bool:
+    """
+    Check that a session ID is safe to use as a file name.
+
+    Examples:
+        >>> is_valid_session_id("2025-01-01T12:00:00.000001")
+        True
+        >>> is_valid_session_id("../../../../pwned_by_import")
+        False
+        >>> is_valid_session_id("..")
+        False
+        >>> is_valid_session_id(42)
+        False
+    """
+    return isinstance(session_id, str) and bool(
+        SESSION_ID_PATTERN.fullmatch(session_id)
+    )
+
+
+def validate_state(state: dict[str, Any]) -> list[str]:
+    """
+    Check that session state has the fields needed to restore it.
+
+    Examples:
+        >>> validate_state({"session_id": "s", "beliefs": {}})
+        ['Missing field: question_history', 'Missing field: question_count']
+        >>> bad = {"session_id": "s", "question_history": [], "question_count": 0,
+        ...        "beliefs": {"purpose": {"hypotheses": ["a"]}}}
+        >>> validate_state(bad)
+        ["Belief 'purpose' is missing 'alpha'"]
+        >>> validate_state({**bad, "session_id": "../x", "beliefs": []})
+        ["Invalid session_id: '../x'", 'beliefs must be an object']
+    """
+    errors = [f"Missing field: {f}" for f in REQUIRED_FIELDS if f not in state]
+
+    if "session_id" in state and not is_valid_session_id(state["session_id"]):
+        errors.append(f"Invalid session_id: {state['session_id']!r}")
+
+    beliefs = state.get("beliefs", {})
+    if not isinstance(beliefs, dict):
+        errors.append("beliefs must be an object")
+        return errors
+
+    for dim_id, belief in beliefs.items():
+        if not isinstance(belief, dict):
+            errors.append(f"Belief '{dim_id}' must be an object")
+            continue
+        for key in ("hypotheses", "alpha"):
+            if key not in belief:
+                errors.append(f"Belief '{dim_id}' is missing '{key}'")
+
+    return errors
+
+
+def export_snapshot(
+    state: dict[str, Any], journal: list[dict[str, Any]]
+) -> dict[str, Any]:
+    """
+    Build a portable snapshot of a session.
+
+    Args:
+        state: Session file contents
+        journal: Observation journal entries
+
+    Returns:
+        Snapshot with format, schema_version, exported_at, state, journal
+
+    Examples:
+        >>> state = {"session_id": "s", "beliefs": {}, "question_history": [],
+        ...          "question_count": 0}
+        >>> snapshot = export_snapshot(state, [])
+        >>> snapshot["format"], snapshot["schema_version"]
+        ('with-me-session', 2)
+        >>> snapshot["state"]["schema_version"]
+        2
+    """
+    return {
+        "format": SNAPSHOT_FORMAT,
+        "schema_version": SCHEMA_VERSION,
+        "exported_at": datetime.now(tz=UTC).isoformat(),
+        "state": migrate_state(state),
+        "journal": journal,
+    }
+
+
+def import_snapshot(
+    data: Any,
+) -> tuple[dict[str, Any], list[dict[str, Any]]]:
+    """
+    Unpack a snapshot (or a bare session file) into migrated state and journal.
+
+    Args:
+        data: Snapshot from export_snapshot, or session file contents
+
+    Returns:
+        (state, journal)
+
+    Raises:
+        ValueError: If the data is not a valid session
+
+    Examples:
+        >>> state = {"session_id": "s", "beliefs": {}, "question_history": [],
+        ...          "question_count": 0}
+        >>> restored, journal = import_snapshot(export_snapshot(state, []))
+        >>> restored["session_id"], journal
+        ('s', [])
+        >>> # Bare session files (any schema version) are accepted too
+        >>> import_snapshot(state)[0]["schema_version"]
+        2
+        >>> import_snapshot({"format": "other"})
+        Traceback (most recent call last):
+        ...
+        ValueError: Not a with-me session snapshot: other
+        >>> import_snapshot({**state, "session_id": "../../../../pwned_by_import"})
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid session_id: '../../../../pwned_by_import'
+        >>> import_snapshot([1])
+        Traceback (most recent call last):
+        ...
+        ValueError: Snapshot must be a JSON object
+        >>> import_snapshot({"format": "with-me-session", "state": [1]})
+        Traceback (most recent call last):
+        ...
+        ValueError: Snapshot state must be a JSON object
+    """
+    if not isinstance(data, dict):
+        msg = "Snapshot must be a JSON object"
+        raise ValueError(msg)
+
+    if "format" in data:
+        if data["format"] != SNAPSHOT_FORMAT:
+            msg = f"Not a with-me session snapshot: {data['format']}"
+            raise ValueError(msg)
+        state = data.get("state", {})
+        journal = data.get("journal", [])
+    else:
+        state = data
+        journal = []
+
+    if not isinstance(state, dict):
+        msg = "Snapshot state must be a JSON object"
+        raise ValueError(msg)
+    if not isinstance(journal, list) or not all(
+        isinstance(entry, dict) for entry in journal
+    ):
+        msg = "Snapshot journal must be a list of objects"
+        raise ValueError(msg)
+
+    errors = validate_state(state)
+    if errors:
+        msg = "; ".join(errors)
+        raise ValueError(msg)
+
+    return migrate_state(state), journal
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python session_schema.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
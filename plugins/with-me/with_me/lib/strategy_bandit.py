@@ -0,0 +1,274 @@
+#!/usr/bin/env python3
+"""
+Multi-armed bandit over question-selection strategies.
+
+Dimension selection can follow different strategies, and which one works best
+depends on the user. The bandit treats each strategy as an arm and learns,
+across sessions, which one yields the most information per token spent.
+
+Strategies:
+- max_eig: Greedy on epistemic entropy (the default dimension selector)
+- breadth_first: Least-asked accessible dimension first
+- user_comfort: Stay on the previous dimension while it is still open
+
+Reward:
+    Realized information gain (bits) per REWARD_TOKEN_UNIT tokens of question
+    and answer text. Tokens are estimated as characters / CHARS_PER_TOKEN.
+
+Responsibilities:
+- Select a strategy (epsilon-greedy or UCB1)
+- Accumulate per-strategy statistics (persisted by QuestionFeedbackManager)
+- Summarize statistics for analytics output
+"""
+
+import doctest
+import math
+import random
+import sys
+from typing import Any
+
+STRATEGIES = ("max_eig", "breadth_first", "user_comfort")
+ALGORITHMS = ("ucb", "epsilon_greedy")
+
+# Rough token estimate for English text (characters per token)
+CHARS_PER_TOKEN = 4
+# Reward is reported in bits per this many tokens
+REWARD_TOKEN_UNIT = 100
+
+
+def estimate_tokens(*texts: str) -> int:
+    """
+    Estimate the token count of question/answer text.
+
+    Args:
+        texts: Text fragments
+
+    Returns:
+        Estimated tokens (at least 1)
+
+    Examples:
+        >>> estimate_tokens("What will it do?", "Parse logs.")
+        7
+        >>> estimate_tokens("")
+        1
+    """
+    chars = sum(len(t) for t in texts)
+    return max(1, round(chars / CHARS_PER_TOKEN))
+
+
+def compute_reward(information_gain: float, tokens: int) -> float:
+    """
+    Compute reward as information gain per REWARD_TOKEN_UNIT tokens.
+
+    Negative information gain (a contradicting answer) counts as zero reward.
+
+    Examples:
+        >>> compute_reward(0.5, 50)
+        1.0
+        >>> compute_reward(-0.2, 50)
+        0.0
+    """
+    return max(0.0, information_gain) * REWARD_TOKEN_UNIT / max(1, tokens)
+
+
+class StrategyBandit:
+    """
+    Strategy selector over accumulated per-strategy statistics.
+
+    Statistics are a plain dict so they can be stored in the feedback file:
+        {strategy: {"pulls": int, "total_reward": float,
+                    "total_information_gain": float, "total_tokens": int}}
+
+    Examples:
+        >>> bandit = StrategyBandit()
+        >>> rng = random.Random(0)
+        >>> # UCB tries every strategy once before exploiting
+        >>> tried = set()
+        >>> for _ in STRATEGIES:
+        ...     s = bandit.select(rng)
+        ...     tried.add(s)
+        ...     _ = bandit.record(s, 0.5 if s == "breadth_first" else 0.05, 50)
+        >>> sorted(tried) == sorted(STRATEGIES)
+        True
+        >>> bandit.select(rng)
+        'breadth_first'
+    """
+
+    def __init__(
+        self,
+        stats: dict[str, dict[str, Any]] | None = None,
+        algorithm: str = "ucb",
+        epsilon: float = 0.1,
+        exploration: float = 1.0,
+    ):
+        """
+        Initialize bandit.
+
+        Args:
+            stats: Accumulated statistics (modified in place by record())
+            algorithm: "ucb" or "epsilon_greedy"
+            epsilon: Exploration rate for epsilon-greedy
+            exploration: UCB exploration coefficient c in
+                         mean + c * sqrt(2 ln N / n)
+
+        Raises:
+            ValueError: If algorithm is unknown
+        """
+        if algorithm not in ALGORITHMS:
+            msg = f"Unknown bandit algorithm: {algorithm}"
+            raise ValueError(msg)
+
+        self.stats = stats if stats is not None else {}
+        for strategy in STRATEGIES:
+            self.stats.setdefault(
+                strategy,
+                {
+                    "pulls": 0,
+                    "total_reward": 0.0,
+                    "total_information_gain": 0.0,
+                    "total_tokens": 0,
+                },
+            )
+        self.algorithm = algorithm
+        self.epsilon = epsilon
+        self.exploration = exploration
+
+    def mean_reward(self, strategy: str) -> float:
+        """Average reward of a strategy (0.0 if never pulled)."""
+        s = self.stats[strategy]
+        return s["total_reward"] / s["pulls"] if s["pulls"] > 0 else 0.0
+
+    def select(self, rng: random.Random | None = None) -> str:
+        """
+        Select a strategy.
+
+        Args:
+            rng: Random source (defaults to a fresh generator)
+
+        Returns:
+            Strategy ID
+
+        Examples:
+            >>> bandit = StrategyBandit(algorithm="epsilon_greedy", epsilon=0.0)
+            >>> _ = bandit.record("user_comfort", 0.4, 40)
+            >>> bandit.select(random.Random(1))
+            'user_comfort'
+        """
+        rng = rng or random.Random()
+
+        if self.algorithm == "epsilon_greedy":
+            if rng.random() < self.epsilon:
+                return rng.choice(STRATEGIES)
+            return max(STRATEGIES, key=self.mean_reward)
+
+        # UCB1: untried strategies first, in declaration order
+        for strategy in STRATEGIES:
+            if self.stats[strategy]["pulls"] == 0:
+                return strategy
+
+        total_pulls = sum(self.stats[s]["pulls"] for s in STRATEGIES)
+
+        def ucb(strategy: str) -> float:
+            pulls = self.stats[strategy]["pulls"]
+            bonus = math.sqrt(2 * math.log(total_pulls) / pulls)
+            return self.mean_reward(strategy) + self.exploration * bonus
+
+        return max(STRATEGIES, key=ucb)
+
+    def record(self, strategy: str, information_gain: float, tokens: int) -> float:
+        """
+        Record the outcome of a question asked under a strategy.
+
+        Args:
+            strategy: Strategy that selected the question
+            information_gain: Realized information gain in bits
+            tokens: Tokens spent on the question and answer
+
+        Returns:
+            Reward credited to the strategy
+
+        Raises:
+            ValueError: If strategy is unknown
+
+        Examples:
+            >>> bandit = StrategyBandit()
+            >>> bandit.record("max_eig", 0.3, 30)
+            1.0
+            >>> bandit.stats["max_eig"]["pulls"]
+            1
+            >>> bandit.record("random", 0.3, 30)
+            Traceback (most recent call last):
+            ...
+            ValueError: Unknown strategy: random
+        """
+        if strategy not in self.stats:
+            msg = f"Unknown strategy: {strategy}"
+            raise ValueError(msg)
+
+        reward = compute_reward(information_gain, tokens)
+        s = self.stats[strategy]
+        s["pulls"] += 1
+        s["total_reward"] += reward
+        s["total_information_gain"] += information_gain
+        s["total_tokens"] += tokens
+        return reward
+
+    def summary(self) -> list[dict[str, Any]]:
+        """
+        Per-strategy averages, best mean reward first.
+
+        Examples:
+            >>> bandit = StrategyBandit()
+            >>> _ = bandit.record("breadth_first", 0.2, 40)
+            >>> row = bandit.summary()[0]
+            >>> row["strategy"], row["pulls"], row["mean_reward"]
+            ('breadth_first', 1, 0.5)
+        """
+        rows = []
+        for strategy in STRATEGIES:
+            s = self.stats[strategy]
+            pulls = s["pulls"]
+            rows.append(
+                {
+                    "strategy": strategy,
+                    "pulls": pulls,
+                    "mean_reward": round(self.mean_reward(strategy), 4),
+                    "mean_information_gain": round(
+                        s["total_information_gain"] / pulls, 4
+                    )
+                    if pulls > 0
+                    else 0.0,
+                    "mean_tokens": round(s["total_tokens"] / pulls, 1)
+                    if pulls > 0
+                    else 0.0,
+                }
+            )
+        rows.sort(key=lambda r: r["mean_reward"], reverse=True)
+        return rows
+
+
+# CLI interface
+def main():
+    """Command-line usage."""
+    min_argc = 2
+    if len(sys.argv) < min_argc:
+        print("Usage: python strategy_bandit.py <command>")
+        print("\nCommands:")
+        print("  test     - Run doctests")
+        sys.exit(1)
+
+    if sys.argv[1] == "test":
+        print("Running doctests...")
+        result = doctest.testmod()
+        if result.failed == 0:
+            print("✓ All doctests passed")
+        else:
+            print(f"✗ {result.failed} doctest(s) failed")
+            sys.exit(1)
+    else:
+        print(f"Unknown command: {sys.argv[1]}")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
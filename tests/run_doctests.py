@@ -85,7 +85,7 @@ def discover_and_test(root: Path, verbose: bool = False) -> tuple[int, int]:
                     py_files.extend(sorted(lib_dir.glob("*.py")))
 
                 # Then add other files
-                for subdir in ["commands", "hooks"]:
+                for subdir in ["cli", "commands", "hooks"]:
                     subdir_path = plugin_root / plugin_pkg / subdir
                     if subdir_path.exists():
                         py_files.extend(sorted(subdir_path.glob("*.py")))